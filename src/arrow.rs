@@ -0,0 +1,372 @@
+use std::marker::{Tuple, PhantomData};
+
+use crate::ConcatArgs;
+
+/// Trait for combining two functions taking the same arguments into one, returning a pair of both their outputs.
+/// 
+/// h(x) = f &&& g = (f(x), g(x))
+/// 
+/// The arguments must implement Clone, as both functions are given a copy of them.
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// Combined with [compose_splat](crate::ComposeSplat::compose_splat), this can be used to express diamonds like h(f₁(x), f₂(x)).
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // h ∘ (f₁ &&& f₂)
+/// // where
+/// // h :: f32 -> f32 -> f32
+/// // f₁ :: f32 -> f32
+/// // f₂ :: f32 -> f32
+/// // h ∘ (f₁ &&& f₂) :: f32 -> f32
+/// let h = |x: f32, y: f32| x - y;
+/// let f1 = |x: f32| x*x;
+/// let f2 = |x: f32| x + 1.0;
+/// 
+/// let f = f1.fanout(f2);
+/// 
+/// let x = 3.0;
+/// 
+/// assert_eq!(f(x), (f1(x), f2(x)));
+/// 
+/// let hf = h.compose_splat(f);
+/// 
+/// assert_eq!(hf(x), h(f1(x), f2(x)));
+/// ```
+#[const_trait]
+pub trait ArrowFanout<G, X>: Sized
+{
+    /// Combining two functions taking the same arguments
+    /// 
+    /// h(x) = f &&& g = (f(x), g(x))
+    fn fanout(self, with: G) -> Fanout<Self, G, X>;
+}
+
+impl<F, G, X> const ArrowFanout<G, X> for F
+where
+    X: Tuple + Clone,
+    Self: FnOnce<X>,
+    G: FnOnce<X>
+{
+    fn fanout(self, with: G) -> Fanout<Self, G, X>
+    {
+        Fanout {
+            f: self,
+            g: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing two functions taking the same arguments, called as one returning a pair of their outputs.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8| x + y;
+/// let g = |x: u8, y: u8| x*y;
+/// 
+/// let fg = f.fanout(g);
+/// 
+/// assert_eq!(fg(2, 3), (5, 6));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Fanout<F, G, X>
+{
+    f: F,
+    g: G,
+    phantom: PhantomData<X>,
+}
+
+impl<F, G, X> FnOnce<X> for Fanout<F, G, X>
+where
+    X: Tuple + Clone,
+    F: FnOnce<X>,
+    G: FnOnce<X>
+{
+    type Output = (F::Output, G::Output);
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        (self.f.call_once(args.clone()), self.g.call_once(args))
+    }
+}
+
+impl<F, G, X> FnMut<X> for Fanout<F, G, X>
+where
+    X: Tuple + Clone,
+    F: FnMut<X>,
+    G: FnMut<X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        (self.f.call_mut(args.clone()), self.g.call_mut(args))
+    }
+}
+
+impl<F, G, X> Fn<X> for Fanout<F, G, X>
+where
+    X: Tuple + Clone,
+    F: Fn<X>,
+    G: Fn<X>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        (self.f.call(args.clone()), self.g.call(args))
+    }
+}
+
+/// Trait for combining two functions into one, taking the arguments of both and returning a pair of both their outputs.
+/// 
+/// h(x, y) = f *** g = (f(x), g(y))
+/// 
+/// The arguments of f come first, then the arguments of g.
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // f *** g
+/// // where
+/// // f :: u8 -> f32
+/// // g :: bool -> bool -> bool
+/// // f *** g :: u8 -> bool -> bool -> (f32, bool)
+/// let f = |x: u8| x as f32;
+/// let g = |x: bool, y: bool| x && y;
+/// 
+/// let fg = f.product(g);
+/// 
+/// let x = 1;
+/// let y = true;
+/// let z = false;
+/// 
+/// assert_eq!(fg(x, y, z), (f(x), g(y, z)));
+/// ```
+#[const_trait]
+pub trait ArrowProduct<G, XF, XG>: Sized
+{
+    /// Combining two functions side by side
+    /// 
+    /// h(x, y) = f *** g = (f(x), g(y))
+    fn product(self, with: G) -> Product<Self, G, XF, XG>;
+}
+
+impl<F, G, XF, XG> const ArrowProduct<G, XF, XG> for F
+where
+    XF: Tuple,
+    XG: Tuple,
+    Self: FnOnce<XF>,
+    G: FnOnce<XG>,
+    (XF, XG): ConcatArgs
+{
+    fn product(self, with: G) -> Product<Self, G, XF, XG>
+    {
+        Product {
+            f: self,
+            g: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing two functions called side by side as one, returning a pair of their outputs.
+/// 
+/// When calling it as a function, the arguments of the first function come first, then the arguments of the second function.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| x + 1;
+/// let g = |x: u8| x*2;
+/// 
+/// let fg = f.product(g);
+/// 
+/// assert_eq!(fg(1, 2), (2, 4));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Product<F, G, XF, XG>
+{
+    f: F,
+    g: G,
+    phantom: PhantomData<(XF, XG)>,
+}
+
+impl<F, G, XF, XG> FnOnce<<(XF, XG) as ConcatArgs>::Type> for Product<F, G, XF, XG>
+where
+    XF: Tuple,
+    XG: Tuple,
+    F: FnOnce<XF>,
+    G: FnOnce<XG>,
+    (XF, XG): ConcatArgs
+{
+    type Output = (F::Output, G::Output);
+
+    extern "rust-call" fn call_once(self, args: <(XF, XG) as ConcatArgs>::Type) -> Self::Output
+    {
+        let (left, right) = <(XF, XG) as ConcatArgs>::split_args(args);
+        (self.f.call_once(left), self.g.call_once(right))
+    }
+}
+
+impl<F, G, XF, XG> FnMut<<(XF, XG) as ConcatArgs>::Type> for Product<F, G, XF, XG>
+where
+    XF: Tuple,
+    XG: Tuple,
+    F: FnMut<XF>,
+    G: FnMut<XG>,
+    (XF, XG): ConcatArgs
+{
+    extern "rust-call" fn call_mut(&mut self, args: <(XF, XG) as ConcatArgs>::Type) -> Self::Output
+    {
+        let (left, right) = <(XF, XG) as ConcatArgs>::split_args(args);
+        (self.f.call_mut(left), self.g.call_mut(right))
+    }
+}
+
+impl<F, G, XF, XG> Fn<<(XF, XG) as ConcatArgs>::Type> for Product<F, G, XF, XG>
+where
+    XF: Tuple,
+    XG: Tuple,
+    F: Fn<XF>,
+    G: Fn<XG>,
+    (XF, XG): ConcatArgs
+{
+    extern "rust-call" fn call(&self, args: <(XF, XG) as ConcatArgs>::Type) -> Self::Output
+    {
+        let (left, right) = <(XF, XG) as ConcatArgs>::split_args(args);
+        (self.f.call(left), self.g.call(right))
+    }
+}
+
+/// Lifts a unary function to act on the first element of a pair, passing the second element through.
+/// 
+/// first(f)(x, y) = (f(x), y)
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| x as f32;
+/// 
+/// assert_eq!(first(f)(1, "test"), (1.0, "test"));
+/// ```
+pub const fn first<F>(f: F) -> First<F>
+{
+    First {
+        f
+    }
+}
+
+/// Lifts a unary function to act on the second element of a pair, passing the first element through.
+/// 
+/// second(f)(x, y) = (x, f(y))
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| x as f32;
+/// 
+/// assert_eq!(second(f)("test", 1), ("test", 1.0));
+/// ```
+pub const fn second<F>(f: F) -> Second<F>
+{
+    Second {
+        f
+    }
+}
+
+/// A struct representing a unary function acting on the first element of a pair.
+/// 
+/// See [first](first).
+#[derive(Clone, Copy, Debug)]
+pub struct First<F>
+{
+    f: F
+}
+
+impl<F, A, C> FnOnce<(A, C)> for First<F>
+where
+    F: FnOnce<(A,)>
+{
+    type Output = (F::Output, C);
+
+    extern "rust-call" fn call_once(self, (a, c): (A, C)) -> Self::Output
+    {
+        (self.f.call_once((a,)), c)
+    }
+}
+
+impl<F, A, C> FnMut<(A, C)> for First<F>
+where
+    F: FnMut<(A,)>
+{
+    extern "rust-call" fn call_mut(&mut self, (a, c): (A, C)) -> Self::Output
+    {
+        (self.f.call_mut((a,)), c)
+    }
+}
+
+impl<F, A, C> Fn<(A, C)> for First<F>
+where
+    F: Fn<(A,)>
+{
+    extern "rust-call" fn call(&self, (a, c): (A, C)) -> Self::Output
+    {
+        (self.f.call((a,)), c)
+    }
+}
+
+/// A struct representing a unary function acting on the second element of a pair.
+/// 
+/// See [second](second).
+#[derive(Clone, Copy, Debug)]
+pub struct Second<F>
+{
+    f: F
+}
+
+impl<F, C, A> FnOnce<(C, A)> for Second<F>
+where
+    F: FnOnce<(A,)>
+{
+    type Output = (C, F::Output);
+
+    extern "rust-call" fn call_once(self, (c, a): (C, A)) -> Self::Output
+    {
+        (c, self.f.call_once((a,)))
+    }
+}
+
+impl<F, C, A> FnMut<(C, A)> for Second<F>
+where
+    F: FnMut<(A,)>
+{
+    extern "rust-call" fn call_mut(&mut self, (c, a): (C, A)) -> Self::Output
+    {
+        (c, self.f.call_mut((a,)))
+    }
+}
+
+impl<F, C, A> Fn<(C, A)> for Second<F>
+where
+    F: Fn<(A,)>
+{
+    extern "rust-call" fn call(&self, (c, a): (C, A)) -> Self::Output
+    {
+        (c, self.f.call((a,)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,301 @@
+use std::future::Future;
+use std::marker::{Tuple, PhantomData};
+use std::ops::AsyncFnOnce;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength, TupleUnprepend, Head, Tail};
+
+use tuple_split::SplitInto;
+
+/// Trait for composing two asynchronous functions, where the output of f is awaited before being passed into g.
+/// 
+/// h(..., x) = g ∘ f = g(f(x).await, ...).await
+/// 
+/// Both operands may be closures returning futures, async closures, or anything else implementing [AsyncFnOnce](std::ops::AsyncFnOnce).
+/// Calling the composition returns a future, so the composition is an asynchronous function itself, and may be composed further.
+/// 
+/// As with [compose](crate::Compose::compose), the leftover arguments of g come first, then the arguments of f.
+/// 
+/// Both operands must implement AsyncFnOnce. Since each future returned by the composition owns its own copy of g, the composition also implements FnMut or Fn if f is a function returning a future implementing FnMut or Fn, and g implements Clone.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::future::{Future, ready};
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// 
+/// use currycompose::*;
+/// 
+/// // a minimal executor
+/// fn block_on<F: Future>(future: F) -> F::Output
+/// {
+///     let mut future = pin!(future);
+///     let mut cx = Context::from_waker(Waker::noop());
+///     loop
+///     {
+///         if let Poll::Ready(y) = future.as_mut().poll(&mut cx)
+///         {
+///             return y
+///         }
+///     }
+/// }
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: u32 -> u32 -> impl Future<Output = u32>
+/// // f :: &str -> impl Future<Output = u32>
+/// // g ∘ f :: u32 -> &str -> impl Future<Output = u32>
+/// let g = async |x: u32, y: u32| x*y;
+/// let f = |x: &str| ready(x.len() as u32);
+/// 
+/// let gf = g.async_compose(f);
+/// 
+/// assert_eq!(block_on(gf(2, "four")), 8);
+/// 
+/// // the composition may be composed further
+/// let h = |x: u32| ready(x + 1);
+/// 
+/// let hgf = h.async_compose(gf);
+/// 
+/// assert_eq!(block_on(hgf(2, "four")), 9);
+/// ```
+/// 
+/// Futures which are not ready right away are awaited as usual, and compositions of functions returning futures may be called several times.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::future::{Future, ready};
+/// use std::pin::{Pin, pin};
+/// use std::task::{Context, Poll, Waker};
+/// 
+/// use currycompose::*;
+/// 
+/// // a minimal executor
+/// fn block_on<F: Future>(future: F) -> F::Output
+/// {
+///     let mut future = pin!(future);
+///     let mut cx = Context::from_waker(Waker::noop());
+///     loop
+///     {
+///         if let Poll::Ready(y) = future.as_mut().poll(&mut cx)
+///         {
+///             return y
+///         }
+///     }
+/// }
+/// 
+/// // a future which is pending once
+/// struct YieldNow(bool);
+/// 
+/// impl Future for YieldNow
+/// {
+///     type Output = ();
+/// 
+///     fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()>
+///     {
+///         if self.0
+///         {
+///             return Poll::Ready(())
+///         }
+///         self.0 = true;
+///         Poll::Pending
+///     }
+/// }
+/// 
+/// let greeting = String::from("hello, ");
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: String -> impl Future<Output = usize>
+/// // f :: &str -> impl Future<Output = String>
+/// // g ∘ f :: &str -> impl Future<Output = usize>
+/// let g = |x: String| ready(x.len());
+/// let f = move |x: &str| {
+///     let y = greeting.clone() + x;
+///     async move {
+///         YieldNow(false).await;
+///         y
+///     }
+/// };
+/// 
+/// let gf = g.async_compose(f);
+/// 
+/// assert_eq!(block_on(gf("world")), 12);
+/// assert_eq!(block_on(gf("you")), 10);
+/// ```
+#[const_trait]
+pub trait AsyncCompose<F, XG, XF>: Sized
+{
+    /// Composing two asynchronous functions
+    /// 
+    /// h(x) = g ∘ f = g(f(x).await).await
+    fn async_compose(self, with: F) -> AsyncComposition<Self, F, XG, XF>;
+}
+
+impl<G, F, XG, XF> const AsyncCompose<F, XG, XF> for G
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    Self: AsyncFnOnce<XG>,
+    F: AsyncFnOnce<XF, Output = Head<XG>>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple,
+    AsyncComposition<Self, F, XG, XF>: FnOnce<ConcatTuples<Tail<XG>, XF>>
+{
+    fn async_compose(self, with: F) -> AsyncComposition<Self, F, XG, XF>
+    {
+        AsyncComposition {
+            g: self,
+            f: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing an asynchronous function composed with another.
+/// 
+/// When calling the composition as a function, the leftover arguments of the composition function come first (if curried), then the arguments of the function being composed with.
+/// 
+/// See [async_compose](AsyncCompose::async_compose).
+#[derive(Clone, Copy, Debug)]
+pub struct AsyncComposition<G, F, XG, XF>
+{
+    g: G,
+    f: F,
+    phantom: PhantomData<(XG, XF)>
+}
+
+enum AsyncComposedState<FF, FG>
+{
+    First(FF),
+    Second(FG)
+}
+
+/// The future returned by an [asynchronous composition](AsyncComposition), which awaits f, then calls and awaits g.
+/// 
+/// FF is the future returned by f.
+pub struct AsyncComposed<G, XG, FF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    G: AsyncFnOnce<XG>
+{
+    g: Option<(G, Tail<XG>)>,
+    state: AsyncComposedState<FF, G::CallOnceFuture>
+}
+
+impl<G, XG, FF> Future for AsyncComposed<G, XG, FF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    G: AsyncFnOnce<XG>,
+    FF: Future<Output = Head<XG>>,
+    ((Head<XG>,), Tail<XG>): TupleConcat<(Head<XG>,), Tail<XG>, Type = XG>
+{
+    type Output = G::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        // SAFETY: the futures are only ever dropped in place, while g and its arguments are never pinned
+        let this = unsafe {self.get_unchecked_mut()};
+        loop
+        {
+            match &mut this.state
+            {
+                AsyncComposedState::First(future) => {
+                    let x = match unsafe {Pin::new_unchecked(future)}.poll(cx)
+                    {
+                        Poll::Ready(x) => x,
+                        Poll::Pending => return Poll::Pending
+                    };
+                    let (g, left) = this.g.take().unwrap();
+                    this.state = AsyncComposedState::Second(g.async_call_once(concat_tuples((x,), left)));
+                },
+                AsyncComposedState::Second(future) => return unsafe {Pin::new_unchecked(future)}.poll(cx)
+            }
+        }
+    }
+}
+
+impl<G, F, XG, XF> FnOnce<ConcatTuples<Tail<XG>, XF>> for AsyncComposition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: AsyncFnOnce<XG>,
+    F: AsyncFnOnce<XF, Output = Head<XG>>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:
+{
+    type Output = AsyncComposed<G, XG, F::CallOnceFuture>;
+
+    extern "rust-call" fn call_once(self, args: ConcatTuples<Tail<XG>, XF>) -> Self::Output
+    {
+        let (left, right): (Tail<XG>, XF) = args.split_tuple();
+        AsyncComposed {
+            g: Some((self.g, left)),
+            state: AsyncComposedState::First(self.f.async_call_once(right))
+        }
+    }
+}
+
+impl<G, F, XG, XF> FnMut<ConcatTuples<Tail<XG>, XF>> for AsyncComposition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: AsyncFnOnce<XG> + Clone,
+    F: FnMut<XF> + AsyncFnOnce<XF, Output = Head<XG>, CallOnceFuture = <F as FnOnce<XF>>::Output>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:
+{
+    extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<Tail<XG>, XF>) -> Self::Output
+    {
+        let (left, right): (Tail<XG>, XF) = args.split_tuple();
+        AsyncComposed {
+            g: Some((self.g.clone(), left)),
+            state: AsyncComposedState::First(self.f.call_mut(right))
+        }
+    }
+}
+
+impl<G, F, XG, XF> Fn<ConcatTuples<Tail<XG>, XF>> for AsyncComposition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: AsyncFnOnce<XG> + Clone,
+    F: Fn<XF> + AsyncFnOnce<XF, Output = Head<XG>, CallOnceFuture = <F as FnOnce<XF>>::Output>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:
+{
+    extern "rust-call" fn call(&self, args: ConcatTuples<Tail<XG>, XF>) -> Self::Output
+    {
+        let (left, right): (Tail<XG>, XF) = args.split_tuple();
+        AsyncComposed {
+            g: Some((self.g.clone(), left)),
+            state: AsyncComposedState::First(self.f.call(right))
+        }
+    }
+}
+
+impl<G, F, XG, XF> AsyncFnOnce<ConcatTuples<Tail<XG>, XF>> for AsyncComposition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: AsyncFnOnce<XG>,
+    F: AsyncFnOnce<XF, Output = Head<XG>>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:,
+    ((Head<XG>,), Tail<XG>): TupleConcat<(Head<XG>,), Tail<XG>, Type = XG>
+{
+    type Output = G::Output;
+    type CallOnceFuture = AsyncComposed<G, XG, F::CallOnceFuture>;
+
+    extern "rust-call" fn async_call_once(self, args: ConcatTuples<Tail<XG>, XF>) -> Self::CallOnceFuture
+    {
+        self.call_once(args)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,356 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples};
+
+/// Trait for making a function callable with any prefix of its arguments, returning a function of the rest.
+/// 
+/// auto_curry(f)(x)(y, z) = auto_curry(f)(x, y)(z) = auto_curry(f)(x, y, z) = f(x, y, z)
+/// 
+/// This is opt-in, rather than built into [Composition](crate::Composition), since a function accepting more than one argument-list can no longer have its argument types inferred when composed with. Auto-curry a composition once it is fully composed.
+/// 
+/// Calling with a prefix of the arguments returns a [Partial](Partial). Through FnOnce, the function is moved into it. Through FnMut or Fn, the function is cloned into it, so it must implement Clone.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f ∘ f :: u8 -> u8 -> f32
+/// let g = |x: f32, y: f32| x - y;
+/// let f = |x: u8| x as f32;
+/// 
+/// let gff = g.compose(f).compose(f).auto_curry();
+/// 
+/// let x = 3;
+/// let y = 1;
+/// 
+/// assert_eq!(gff(x)(y), gff(x, y));
+/// assert_eq!(gff(x)(y), g(f(x), f(y)));
+/// ```
+/// 
+/// Calling through FnMut or Fn with a prefix requires the function to implement Clone.
+/// 
+/// ```rust,compile_fail,E0277
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// use std::sync::Mutex;
+/// 
+/// let offset = Mutex::new(1);
+/// 
+/// // not Clone, since Mutex is not Clone
+/// let f = move |x: u8, y: u8| *offset.lock().unwrap() + x + y;
+/// let f = f.auto_curry();
+/// 
+/// fn call_by_ref<F: Fn(u8) -> Y, Y>(f: &F) -> Y
+/// {
+///     f(1)
+/// }
+/// 
+/// call_by_ref(&f);
+/// ```
+#[const_trait]
+pub trait AutoCurry<X>: Sized
+{
+    /// Making a function callable with any prefix of its arguments
+    /// 
+    /// auto_curry(f)(x)(y, z) = f(x, y, z)
+    fn auto_curry(self) -> AutoCurried<Self, X>;
+}
+
+impl<F, X> const AutoCurry<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn auto_curry(self) -> AutoCurried<Self, X>
+    {
+        AutoCurried {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function taking the argument-list X, which can also be called with any non-empty prefix of X.
+/// 
+/// See [auto_curry](AutoCurry::auto_curry).
+#[derive(Clone, Copy, Debug)]
+pub struct AutoCurried<F, X>
+{
+    f: F,
+    phantom: PhantomData<X>
+}
+
+impl<F, X> AutoCurried<F, X>
+{
+    /// Unwraps the function.
+    pub fn into_inner(self) -> F
+    {
+        self.f
+    }
+}
+
+impl<F, X> PrefixCallOnce<X, Saturated> for AutoCurried<F, X>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    type Output = F::Output;
+
+    fn call_prefix_once(self, args: X) -> Self::Output
+    {
+        self.f.call_once(args)
+    }
+}
+
+impl<F, X> PrefixCallMut<X, Saturated> for AutoCurried<F, X>
+where
+    X: Tuple,
+    F: FnMut<X>
+{
+    fn call_prefix_mut(&mut self, args: X) -> Self::Output
+    {
+        self.f.call_mut(args)
+    }
+}
+
+impl<F, X> PrefixCall<X, Saturated> for AutoCurried<F, X>
+where
+    X: Tuple,
+    F: Fn<X>
+{
+    fn call_prefix(&self, args: X) -> Self::Output
+    {
+        self.f.call(args)
+    }
+}
+
+impl<F, X, XP> FnOnce<XP> for AutoCurried<F, X>
+where
+    XP: Tuple,
+    X: ArgsPrefix<XP>,
+    Self: PrefixCallOnce<XP, X::Kind>
+{
+    type Output = <Self as PrefixCallOnce<XP, X::Kind>>::Output;
+
+    extern "rust-call" fn call_once(self, args: XP) -> Self::Output
+    {
+        self.call_prefix_once(args)
+    }
+}
+
+impl<F, X, XP> FnMut<XP> for AutoCurried<F, X>
+where
+    XP: Tuple,
+    X: ArgsPrefix<XP>,
+    Self: PrefixCallMut<XP, X::Kind>
+{
+    extern "rust-call" fn call_mut(&mut self, args: XP) -> Self::Output
+    {
+        self.call_prefix_mut(args)
+    }
+}
+
+impl<F, X, XP> Fn<XP> for AutoCurried<F, X>
+where
+    XP: Tuple,
+    X: ArgsPrefix<XP>,
+    Self: PrefixCall<XP, X::Kind>
+{
+    extern "rust-call" fn call(&self, args: XP) -> Self::Output
+    {
+        self.call_prefix(args)
+    }
+}
+
+/// A struct representing a function with a prefix of its arguments already given, which takes the rest of its arguments.
+/// 
+/// partial(f, x)(y, z) = f(x, y, z)
+/// 
+/// This is what an [auto-curried](AutoCurry::auto_curry) function returns when called with only a prefix of its arguments.
+/// 
+/// When called through FnOnce, the arguments given so far are moved into the function.
+/// When called through FnMut or Fn, they are cloned, so XP must implement Clone.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8, z: u8| x*100 + y*10 + z;
+/// let f = f.auto_curry();
+/// 
+/// let f1 = f(1);
+/// 
+/// assert_eq!(f1(2, 3), 123);
+/// assert_eq!(f1(4, 5), 145);
+/// 
+/// // a partial of an auto-curried function can itself be called with a prefix
+/// assert_eq!(f1(2)(3), 123);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Partial<F, XP>
+{
+    f: F,
+    args: XP
+}
+
+impl<F, XP, XR> FnOnce<XR> for Partial<F, XP>
+where
+    XP: Tuple,
+    XR: Tuple,
+    (XP, XR): TupleConcat<XP, XR>,
+    ConcatTuples<XP, XR>: Tuple,
+    F: FnOnce<ConcatTuples<XP, XR>>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: XR) -> Self::Output
+    {
+        self.f.call_once(concat_tuples(self.args, args))
+    }
+}
+
+impl<F, XP, XR> FnMut<XR> for Partial<F, XP>
+where
+    XP: Tuple + Clone,
+    XR: Tuple,
+    (XP, XR): TupleConcat<XP, XR>,
+    ConcatTuples<XP, XR>: Tuple,
+    F: FnMut<ConcatTuples<XP, XR>>
+{
+    extern "rust-call" fn call_mut(&mut self, args: XR) -> Self::Output
+    {
+        self.f.call_mut(concat_tuples(self.args.clone(), args))
+    }
+}
+
+impl<F, XP, XR> Fn<XR> for Partial<F, XP>
+where
+    XP: Tuple + Clone,
+    XR: Tuple,
+    (XP, XR): TupleConcat<XP, XR>,
+    ConcatTuples<XP, XR>: Tuple,
+    F: Fn<ConcatTuples<XP, XR>>
+{
+    extern "rust-call" fn call(&self, args: XR) -> Self::Output
+    {
+        self.f.call(concat_tuples(self.args.clone(), args))
+    }
+}
+
+/// Marks an argument-list given in full.
+#[doc(hidden)]
+pub struct Saturated;
+
+/// Marks an argument-list given only in part.
+#[doc(hidden)]
+pub struct Unsaturated;
+
+/// Helper trait for telling whether P is all of an argument-list, or only a non-empty prefix of it.
+#[doc(hidden)]
+pub trait ArgsPrefix<P>: Tuple
+{
+    type Kind;
+}
+
+impl ArgsPrefix<()> for ()
+{
+    type Kind = Saturated;
+}
+
+macro_rules! impl_args_prefix {
+    ([$($p:ident),+] []) => {
+        impl<$($p,)+> ArgsPrefix<($($p,)+)> for ($($p,)+)
+        {
+            type Kind = Saturated;
+        }
+    };
+    ([$($p:ident),+] [$r0:ident $(, $r:ident)*]) => {
+        impl<$($p,)+ $r0, $($r,)*> ArgsPrefix<($($p,)+)> for ($($p,)+ $r0, $($r,)*)
+        {
+            type Kind = Unsaturated;
+        }
+
+        impl_args_prefix!([$($p,)+ $r0] [$($r),*]);
+    };
+}
+
+macro_rules! impl_args_prefixes {
+    ($t0:ident $(, $t:ident)*) => {
+        impl_args_prefix!([$t0] [$($t),*]);
+
+        impl_args_prefixes!($($t),*);
+    };
+    () => {};
+}
+
+impl_args_prefixes!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+/// Helper trait for calling a function with either all of its arguments, or a prefix of them, depending on K.
+#[doc(hidden)]
+pub trait PrefixCallOnce<X, K>
+{
+    type Output;
+
+    fn call_prefix_once(self, args: X) -> Self::Output;
+}
+
+/// Helper trait for calling a function with either all of its arguments, or a prefix of them, depending on K.
+#[doc(hidden)]
+pub trait PrefixCallMut<X, K>: PrefixCallOnce<X, K>
+{
+    fn call_prefix_mut(&mut self, args: X) -> Self::Output;
+}
+
+/// Helper trait for calling a function with either all of its arguments, or a prefix of them, depending on K.
+#[doc(hidden)]
+pub trait PrefixCall<X, K>: PrefixCallMut<X, K>
+{
+    fn call_prefix(&self, args: X) -> Self::Output;
+}
+
+impl<F, XP> PrefixCallOnce<XP, Unsaturated> for F
+{
+    type Output = Partial<F, XP>;
+
+    fn call_prefix_once(self, args: XP) -> Self::Output
+    {
+        Partial {
+            f: self,
+            args
+        }
+    }
+}
+
+impl<F, XP> PrefixCallMut<XP, Unsaturated> for F
+where
+    F: Clone
+{
+    fn call_prefix_mut(&mut self, args: XP) -> Self::Output
+    {
+        Partial {
+            f: self.clone(),
+            args
+        }
+    }
+}
+
+impl<F, XP> PrefixCall<XP, Unsaturated> for F
+where
+    F: Clone
+{
+    fn call_prefix(&self, args: XP) -> Self::Output
+    {
+        Partial {
+            f: self.clone(),
+            args
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,334 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{TupleConcat, concat_tuples, TupleLength, TupleUnprepend, TupleUnappend, TupleAppend, Head, Tail, Init, Last, append};
+
+use tuple_split::{TupleSplit, SplitInto, Left, Right};
+
+use crate::{SkipAt, ArgAt};
+
+/// Trait for fixing the first argument of a function, returning a function of the remaining arguments.
+/// 
+/// bind_first(f, x)(y, z) = f(x, y, z)
+/// 
+/// V is the type of the first argument.
+/// 
+/// If f implements FnMut or Fn, and the fixed argument implements Clone, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8, z: u8| x*100 + y*10 + z;
+/// 
+/// let g = f.bind_first(1);
+/// 
+/// assert_eq!(g(2, 3), f(1, 2, 3));
+/// ```
+#[const_trait]
+pub trait BindFirst<X, V>: Sized
+{
+    /// Fixing the first argument of a function
+    /// 
+    /// bind_first(f, x)(y, z) = f(x, y, z)
+    fn bind_first(self, value: V) -> BoundFirst<Self, X, V>;
+}
+
+impl<F, X> const BindFirst<X, Head<X>> for F
+where
+    X: Tuple + TupleUnprepend<X>,
+    Self: FnOnce<X>
+{
+    fn bind_first(self, value: Head<X>) -> BoundFirst<Self, X, Head<X>>
+    {
+        BoundFirst {
+            f: self,
+            value,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function with its first argument fixed.
+/// 
+/// When called through FnOnce, the fixed argument is moved into the function. When called through FnMut or Fn, it is cloned.
+/// 
+/// See [bind_first](BindFirst::bind_first).
+#[derive(Clone, Copy, Debug)]
+pub struct BoundFirst<F, X, V>
+{
+    f: F,
+    value: V,
+    phantom: PhantomData<X>
+}
+
+impl<F, X, V> FnOnce<Tail<X>> for BoundFirst<F, X, V>
+where
+    X: Tuple + TupleUnprepend<X>,
+    Tail<X>: Tuple,
+    F: FnOnce<X>,
+    ((V,), Tail<X>): TupleConcat<(V,), Tail<X>, Type = X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: Tail<X>) -> Self::Output
+    {
+        self.f.call_once(concat_tuples((self.value,), args))
+    }
+}
+
+impl<F, X, V> FnMut<Tail<X>> for BoundFirst<F, X, V>
+where
+    X: Tuple + TupleUnprepend<X>,
+    Tail<X>: Tuple,
+    V: Clone,
+    F: FnMut<X>,
+    ((V,), Tail<X>): TupleConcat<(V,), Tail<X>, Type = X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: Tail<X>) -> Self::Output
+    {
+        self.f.call_mut(concat_tuples((self.value.clone(),), args))
+    }
+}
+
+impl<F, X, V> Fn<Tail<X>> for BoundFirst<F, X, V>
+where
+    X: Tuple + TupleUnprepend<X>,
+    Tail<X>: Tuple,
+    V: Clone,
+    F: Fn<X>,
+    ((V,), Tail<X>): TupleConcat<(V,), Tail<X>, Type = X>
+{
+    extern "rust-call" fn call(&self, args: Tail<X>) -> Self::Output
+    {
+        self.f.call(concat_tuples((self.value.clone(),), args))
+    }
+}
+
+/// Trait for fixing the last argument of a function, returning a function of the remaining arguments.
+/// 
+/// bind_last(f, z)(x, y) = f(x, y, z)
+/// 
+/// V is the type of the last argument.
+/// 
+/// If f implements FnMut or Fn, and the fixed argument implements Clone, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f :: f32 -> u8 -> f32
+/// let g = |x: f32, y: f32| x - y;
+/// let f = |x: u8| x as f32;
+/// 
+/// // pinning the argument of f, which the composition moved to the end
+/// let gf = g.compose(f).bind_last(2);
+/// 
+/// let y = 1.0;
+/// 
+/// assert_eq!(gf(y), g(f(2), y));
+/// ```
+#[const_trait]
+pub trait BindLast<X, V>: Sized
+{
+    /// Fixing the last argument of a function
+    /// 
+    /// bind_last(f, z)(x, y) = f(x, y, z)
+    fn bind_last(self, value: V) -> BoundLast<Self, X, V>;
+}
+
+impl<F, X> const BindLast<X, Last<X>> for F
+where
+    X: Tuple + TupleUnappend<X>,
+    Self: FnOnce<X>
+{
+    fn bind_last(self, value: Last<X>) -> BoundLast<Self, X, Last<X>>
+    {
+        BoundLast {
+            f: self,
+            value,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function with its last argument fixed.
+/// 
+/// When called through FnOnce, the fixed argument is moved into the function. When called through FnMut or Fn, it is cloned.
+/// 
+/// See [bind_last](BindLast::bind_last).
+#[derive(Clone, Copy, Debug)]
+pub struct BoundLast<F, X, V>
+{
+    f: F,
+    value: V,
+    phantom: PhantomData<X>
+}
+
+impl<F, X, V> FnOnce<Init<X>> for BoundLast<F, X, V>
+where
+    X: Tuple + TupleUnappend<X>,
+    Init<X>: Tuple,
+    F: FnOnce<X>,
+    (Init<X>, V): TupleAppend<Init<X>, V, Type = X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: Init<X>) -> Self::Output
+    {
+        self.f.call_once(append(args, self.value))
+    }
+}
+
+impl<F, X, V> FnMut<Init<X>> for BoundLast<F, X, V>
+where
+    X: Tuple + TupleUnappend<X>,
+    Init<X>: Tuple,
+    V: Clone,
+    F: FnMut<X>,
+    (Init<X>, V): TupleAppend<Init<X>, V, Type = X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: Init<X>) -> Self::Output
+    {
+        self.f.call_mut(append(args, self.value.clone()))
+    }
+}
+
+impl<F, X, V> Fn<Init<X>> for BoundLast<F, X, V>
+where
+    X: Tuple + TupleUnappend<X>,
+    Init<X>: Tuple,
+    V: Clone,
+    F: Fn<X>,
+    (Init<X>, V): TupleAppend<Init<X>, V, Type = X>
+{
+    extern "rust-call" fn call(&self, args: Init<X>) -> Self::Output
+    {
+        self.f.call(append(args, self.value.clone()))
+    }
+}
+
+/// Trait for fixing an arbitrary argument of a function, returning a function of the remaining arguments.
+/// 
+/// bind_at::<1>(f, y)(x, z) = f(x, y, z)
+/// 
+/// The index of the argument is specified as the const generic N. [bind_at::<0>](BindAt::bind_at) is equivalent to [bind_first](BindFirst::bind_first).
+/// 
+/// If f implements FnMut or Fn, and the fixed argument implements Clone, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8, z: u8| x*100 + y*10 + z;
+/// 
+/// let g = f.bind_at::<1>(2);
+/// 
+/// assert_eq!(g(1, 3), f(1, 2, 3));
+/// ```
+#[const_trait]
+pub trait BindAt<X>: Sized
+{
+    /// Fixing argument N of a function
+    /// 
+    /// bind_at::<1>(f, y)(x, z) = f(x, y, z)
+    fn bind_at<const N: usize>(self, value: ArgAt<X, N>) -> BoundAt<Self, X, ArgAt<X, N>, N>
+    where
+        X: TupleSplit<N>,
+        Right<X, N>: TupleUnprepend<Right<X, N>>;
+}
+
+impl<F, X> const BindAt<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn bind_at<const N: usize>(self, value: ArgAt<X, N>) -> BoundAt<Self, X, ArgAt<X, N>, N>
+    where
+        X: TupleSplit<N>,
+        Right<X, N>: TupleUnprepend<Right<X, N>>
+    {
+        BoundAt {
+            f: self,
+            value,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function with argument N fixed.
+/// 
+/// When called through FnOnce, the fixed argument is moved into the function. When called through FnMut or Fn, it is cloned.
+/// 
+/// See [bind_at](BindAt::bind_at).
+#[derive(Clone, Copy, Debug)]
+pub struct BoundAt<F, X, V, const N: usize>
+{
+    f: F,
+    value: V,
+    phantom: PhantomData<X>
+}
+
+impl<F, X, V, const N: usize> FnOnce<SkipAt<X, N>> for BoundAt<F, X, V, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Right<X, N>: TupleUnprepend<Right<X, N>>,
+    F: FnOnce<X>,
+    (Left<X, N>, Tail<Right<X, N>>): TupleConcat<Left<X, N>, Tail<Right<X, N>>>,
+    SkipAt<X, N>: Tuple + SplitInto<Left<X, N>, Tail<Right<X, N>>>,
+    [(); <Left<X, N> as TupleLength>::LENGTH]:,
+    ((V,), Tail<Right<X, N>>): TupleConcat<(V,), Tail<Right<X, N>>, Type = Right<X, N>>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: SkipAt<X, N>) -> Self::Output
+    {
+        let (before, after): (Left<X, N>, Tail<Right<X, N>>) = args.split_tuple();
+        self.f.call_once(concat_tuples(before, concat_tuples((self.value,), after)))
+    }
+}
+
+impl<F, X, V, const N: usize> FnMut<SkipAt<X, N>> for BoundAt<F, X, V, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Right<X, N>: TupleUnprepend<Right<X, N>>,
+    V: Clone,
+    F: FnMut<X>,
+    (Left<X, N>, Tail<Right<X, N>>): TupleConcat<Left<X, N>, Tail<Right<X, N>>>,
+    SkipAt<X, N>: Tuple + SplitInto<Left<X, N>, Tail<Right<X, N>>>,
+    [(); <Left<X, N> as TupleLength>::LENGTH]:,
+    ((V,), Tail<Right<X, N>>): TupleConcat<(V,), Tail<Right<X, N>>, Type = Right<X, N>>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: SkipAt<X, N>) -> Self::Output
+    {
+        let (before, after): (Left<X, N>, Tail<Right<X, N>>) = args.split_tuple();
+        self.f.call_mut(concat_tuples(before, concat_tuples((self.value.clone(),), after)))
+    }
+}
+
+impl<F, X, V, const N: usize> Fn<SkipAt<X, N>> for BoundAt<F, X, V, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Right<X, N>: TupleUnprepend<Right<X, N>>,
+    V: Clone,
+    F: Fn<X>,
+    (Left<X, N>, Tail<Right<X, N>>): TupleConcat<Left<X, N>, Tail<Right<X, N>>>,
+    SkipAt<X, N>: Tuple + SplitInto<Left<X, N>, Tail<Right<X, N>>>,
+    [(); <Left<X, N> as TupleLength>::LENGTH]:,
+    ((V,), Tail<Right<X, N>>): TupleConcat<(V,), Tail<Right<X, N>>, Type = Right<X, N>>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    extern "rust-call" fn call(&self, args: SkipAt<X, N>) -> Self::Output
+    {
+        let (before, after): (Left<X, N>, Tail<Right<X, N>>) = args.split_tuple();
+        self.f.call(concat_tuples(before, concat_tuples((self.value.clone(),), after)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::marker::Tuple;
+
+/// Trait for an argument-list which may be taken from a tuple of slices, one for each argument, by indexing all of them at the same position.
+/// 
+/// This is implemented for argument-lists of up to 6 arguments, where each argument implements Clone.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let slices: (&[u8], &[f32]) = (&[1, 2], &[1.0, 2.0]);
+/// 
+/// assert_eq!(<(u8, f32) as SliceArgs>::slices_len(&slices), Some(2));
+/// assert_eq!(<(u8, f32) as SliceArgs>::get_args(&slices, 1), (2, 2.0));
+/// ```
+pub trait SliceArgs: Tuple
+{
+    /// A tuple of slices, one for each argument.
+    type Slices<'a>
+    where
+        Self: 'a;
+
+    /// The common length of all the slices, or None if they differ.
+    fn slices_len(slices: &Self::Slices<'_>) -> Option<usize>;
+
+    /// Takes the argument-list at the given position of each slice.
+    fn get_args(slices: &Self::Slices<'_>, i: usize) -> Self;
+}
+
+macro_rules! impl_slice_args {
+    (($x0:ident, $s0:ident) $(, ($x:ident, $s:ident))*) => {
+        impl<$x0, $($x,)*> SliceArgs for ($x0, $($x,)*)
+        where
+            $x0: Clone,
+            $($x: Clone,)*
+        {
+            type Slices<'a> = (&'a [$x0], $(&'a [$x],)*)
+            where
+                Self: 'a;
+
+            fn slices_len(slices: &Self::Slices<'_>) -> Option<usize>
+            {
+                let ($s0, $($s,)*) = slices;
+                let len = $s0.len();
+                if true $(&& $s.len() == len)*
+                {
+                    Some(len)
+                }
+                else
+                {
+                    None
+                }
+            }
+
+            fn get_args(slices: &Self::Slices<'_>, i: usize) -> Self
+            {
+                let ($s0, $($s,)*) = slices;
+                ($s0[i].clone(), $($s[i].clone(),)*)
+            }
+        }
+
+        impl_slice_args!($(($x, $s)),*);
+    };
+    () => {};
+}
+
+impl_slice_args!(
+    (X1, x1),
+    (X2, x2),
+    (X3, x3),
+    (X4, x4),
+    (X5, x5),
+    (X6, x6)
+);
+
+/// Trait for calling a function of one argument, such as a composition of per-sample filters, on each element of a slice, writing its outputs into another slice.
+/// 
+/// The function is called through FnMut, in order, so any state it keeps carries over from one element to the next, and from one block to the next.
+/// 
+/// # Panics
+/// 
+/// Panics if the input and output slices have different lengths.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32
+/// // f :: f32 -> f32
+/// // g ∘ f :: f32 -> f32
+/// let mut state = 0.0;
+/// let g = move |x: f32| {state += (x - state)*0.5; state};
+/// let f = |x: f32| x*2.0;
+/// 
+/// let mut gf = g.compose(f);
+/// 
+/// let mut out = [0.0; 4];
+/// 
+/// gf.call_slice(&[1.0, 1.0, 1.0, 1.0], &mut out);
+/// assert_eq!(out, [1.0, 1.5, 1.75, 1.875]);
+/// 
+/// // the filter state carries over to the next block
+/// gf.call_slice(&[0.0, 0.0, 0.0, 0.0], &mut out);
+/// assert_eq!(out, [0.9375, 0.46875, 0.234375, 0.1171875]);
+/// ```
+pub trait CallSlice<X>: FnMut<(X,)>
+{
+    /// Calling a function on each element of a slice
+    fn call_slice(&mut self, input: &[X], out: &mut [<Self as FnOnce<(X,)>>::Output]);
+}
+
+impl<F, X> CallSlice<X> for F
+where
+    X: Clone,
+    Self: FnMut<(X,)>
+{
+    fn call_slice(&mut self, input: &[X], out: &mut [<Self as FnOnce<(X,)>>::Output])
+    {
+        assert_eq!(input.len(), out.len(), "input and output slices must have the same length");
+        for (x, y) in input.iter().zip(out.iter_mut())
+        {
+            *y = self.call_mut((x.clone(),))
+        }
+    }
+}
+
+/// Trait for calling a function on the zipped elements of one slice per argument, writing its outputs into another slice.
+/// 
+/// This is the same as [call_slice](CallSlice::call_slice), but for functions of any number of arguments, up to 6. The slices are given in the same order as the arguments.
+/// 
+/// # Panics
+/// 
+/// Panics if the input slices and the output slice do not all have the same length.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: f32 -> f32
+/// // g ∘ f :: f32 -> f32 -> f32
+/// let mut state = 0.0;
+/// let g = |x: f32, gain: f32| x*gain;
+/// let f = move |x: f32| {state += (x - state)*0.5; state};
+/// 
+/// let mut gf = g.compose(f);
+/// 
+/// let gains = [1.0, 2.0, 4.0, 8.0];
+/// let samples = [1.0, 1.0, 1.0, 1.0];
+/// let mut out = [0.0; 4];
+/// 
+/// // the leftover argument of g comes first
+/// gf.call_slices((&gains, &samples), &mut out);
+/// assert_eq!(out, [0.5, 1.5, 3.5, 7.5]);
+/// ```
+/// 
+/// ```rust,should_panic
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let mut f = |x: u8, y: u8| x + y;
+/// 
+/// let mut out = [0; 2];
+/// 
+/// // the slices have different lengths
+/// f.call_slices((&[1, 2], &[1, 2, 3]), &mut out);
+/// ```
+pub trait CallSlices<X>: FnMut<X>
+where
+    X: SliceArgs
+{
+    /// Calling a function on the zipped elements of one slice per argument
+    fn call_slices(&mut self, inputs: X::Slices<'_>, out: &mut [<Self as FnOnce<X>>::Output]);
+}
+
+impl<F, X> CallSlices<X> for F
+where
+    X: SliceArgs,
+    Self: FnMut<X>
+{
+    fn call_slices(&mut self, inputs: X::Slices<'_>, out: &mut [<Self as FnOnce<X>>::Output])
+    {
+        assert_eq!(X::slices_len(&inputs), Some(out.len()), "input and output slices must have the same length");
+        for (i, y) in out.iter_mut().enumerate()
+        {
+            *y = self.call_mut(X::get_args(&inputs, i))
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,445 @@
+/// A value which is one of two possible types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R>
+{
+    Left(L),
+    Right(R)
+}
+
+/// Trait for sum types with a left and a right variant, which the choice combinators can branch on.
+/// 
+/// For [Result](Result), Ok is the left variant and Err is the right variant, following the order of its type parameters.
+pub trait Choice: Sized
+{
+    /// The type of the left variant.
+    type Left;
+    /// The type of the right variant.
+    type Right;
+    /// The same kind of sum type, with other types for its variants.
+    type Rebind<L, R>: Choice<Left = L, Right = R>;
+
+    /// Converts into an [Either](Either).
+    fn into_either(self) -> Either<Self::Left, Self::Right>;
+    /// Wraps a value as the left variant.
+    fn from_left(left: Self::Left) -> Self;
+    /// Wraps a value as the right variant.
+    fn from_right(right: Self::Right) -> Self;
+}
+
+impl<L, R> Choice for Either<L, R>
+{
+    type Left = L;
+    type Right = R;
+    type Rebind<LL, RR> = Either<LL, RR>;
+
+    fn into_either(self) -> Either<L, R>
+    {
+        self
+    }
+    fn from_left(left: L) -> Self
+    {
+        Either::Left(left)
+    }
+    fn from_right(right: R) -> Self
+    {
+        Either::Right(right)
+    }
+}
+
+impl<T, E> Choice for Result<T, E>
+{
+    type Left = T;
+    type Right = E;
+    type Rebind<LL, RR> = Result<LL, RR>;
+
+    fn into_either(self) -> Either<T, E>
+    {
+        match self
+        {
+            Ok(ok) => Either::Left(ok),
+            Err(err) => Either::Right(err)
+        }
+    }
+    fn from_left(left: T) -> Self
+    {
+        Ok(left)
+    }
+    fn from_right(right: E) -> Self
+    {
+        Err(right)
+    }
+}
+
+/// Lifts a unary function to act on the left variant of a sum type, passing the right variant through.
+/// 
+/// left(f)(Left(x)) = Left(f(x))
+/// 
+/// left(f)(Right(y)) = Right(y)
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = left(|x: u8| x as f32);
+/// 
+/// assert_eq!(f(Ok::<u8, &str>(1)), Ok(1.0));
+/// assert_eq!(f(Err::<u8, &str>("error")), Err("error"));
+/// ```
+pub const fn left<F>(f: F) -> ChoiceLeft<F>
+{
+    ChoiceLeft {
+        f
+    }
+}
+
+/// Lifts a unary function to act on the right variant of a sum type, passing the left variant through.
+/// 
+/// right(f)(Left(x)) = Left(x)
+/// 
+/// right(f)(Right(y)) = Right(f(y))
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = right(|x: &str| x.len());
+/// 
+/// assert_eq!(f(Ok::<u8, &str>(1)), Ok(1));
+/// assert_eq!(f(Err::<u8, &str>("error")), Err(5));
+/// ```
+pub const fn right<F>(f: F) -> ChoiceRight<F>
+{
+    ChoiceRight {
+        f
+    }
+}
+
+/// A struct representing a unary function acting on the left variant of a sum type.
+/// 
+/// See [left](left).
+#[derive(Clone, Copy, Debug)]
+pub struct ChoiceLeft<F>
+{
+    f: F
+}
+
+impl<F, C> FnOnce<(C,)> for ChoiceLeft<F>
+where
+    C: Choice,
+    F: FnOnce<(C::Left,)>
+{
+    type Output = C::Rebind<F::Output, C::Right>;
+
+    extern "rust-call" fn call_once(self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(self.f.call_once((left,))),
+            Either::Right(right) => Choice::from_right(right)
+        }
+    }
+}
+
+impl<F, C> FnMut<(C,)> for ChoiceLeft<F>
+where
+    C: Choice,
+    F: FnMut<(C::Left,)>
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(self.f.call_mut((left,))),
+            Either::Right(right) => Choice::from_right(right)
+        }
+    }
+}
+
+impl<F, C> Fn<(C,)> for ChoiceLeft<F>
+where
+    C: Choice,
+    F: Fn<(C::Left,)>
+{
+    extern "rust-call" fn call(&self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(self.f.call((left,))),
+            Either::Right(right) => Choice::from_right(right)
+        }
+    }
+}
+
+/// A struct representing a unary function acting on the right variant of a sum type.
+/// 
+/// See [right](right).
+#[derive(Clone, Copy, Debug)]
+pub struct ChoiceRight<F>
+{
+    f: F
+}
+
+impl<F, C> FnOnce<(C,)> for ChoiceRight<F>
+where
+    C: Choice,
+    F: FnOnce<(C::Right,)>
+{
+    type Output = C::Rebind<C::Left, F::Output>;
+
+    extern "rust-call" fn call_once(self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(left),
+            Either::Right(right) => Choice::from_right(self.f.call_once((right,)))
+        }
+    }
+}
+
+impl<F, C> FnMut<(C,)> for ChoiceRight<F>
+where
+    C: Choice,
+    F: FnMut<(C::Right,)>
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(left),
+            Either::Right(right) => Choice::from_right(self.f.call_mut((right,)))
+        }
+    }
+}
+
+impl<F, C> Fn<(C,)> for ChoiceRight<F>
+where
+    C: Choice,
+    F: Fn<(C::Right,)>
+{
+    extern "rust-call" fn call(&self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(left),
+            Either::Right(right) => Choice::from_right(self.f.call((right,)))
+        }
+    }
+}
+
+/// Trait for combining two unary functions into one acting on a sum type, where f acts on the left variant and g acts on the right variant.
+/// 
+/// (f +++ g)(Left(x)) = Left(f(x))
+/// 
+/// (f +++ g)(Right(y)) = Right(g(y))
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| x as f32;
+/// let g = |x: &str| x.len();
+/// 
+/// let fg = f.sum(g);
+/// 
+/// assert_eq!(fg(Ok(1)), Ok(1.0));
+/// assert_eq!(fg(Err("error")), Err(5));
+/// ```
+#[const_trait]
+pub trait ArrowSum<G, L, R>: Sized
+{
+    /// Combining two functions acting on each variant of a sum type
+    /// 
+    /// (f +++ g)(Left(x)) = Left(f(x))
+    /// 
+    /// (f +++ g)(Right(y)) = Right(g(y))
+    fn sum(self, with: G) -> ChoiceSum<Self, G>;
+}
+
+impl<F, G, L, R> const ArrowSum<G, L, R> for F
+where
+    Self: FnOnce<(L,)>,
+    G: FnOnce<(R,)>
+{
+    fn sum(self, with: G) -> ChoiceSum<Self, G>
+    {
+        ChoiceSum {
+            f: self,
+            g: with
+        }
+    }
+}
+
+/// A struct representing two unary functions acting on each variant of a sum type.
+/// 
+/// See [sum](ArrowSum::sum).
+#[derive(Clone, Copy, Debug)]
+pub struct ChoiceSum<F, G>
+{
+    f: F,
+    g: G
+}
+
+impl<F, G, C> FnOnce<(C,)> for ChoiceSum<F, G>
+where
+    C: Choice,
+    F: FnOnce<(C::Left,)>,
+    G: FnOnce<(C::Right,)>
+{
+    type Output = C::Rebind<F::Output, G::Output>;
+
+    extern "rust-call" fn call_once(self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(self.f.call_once((left,))),
+            Either::Right(right) => Choice::from_right(self.g.call_once((right,)))
+        }
+    }
+}
+
+impl<F, G, C> FnMut<(C,)> for ChoiceSum<F, G>
+where
+    C: Choice,
+    F: FnMut<(C::Left,)>,
+    G: FnMut<(C::Right,)>
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(self.f.call_mut((left,))),
+            Either::Right(right) => Choice::from_right(self.g.call_mut((right,)))
+        }
+    }
+}
+
+impl<F, G, C> Fn<(C,)> for ChoiceSum<F, G>
+where
+    C: Choice,
+    F: Fn<(C::Left,)>,
+    G: Fn<(C::Right,)>
+{
+    extern "rust-call" fn call(&self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => Choice::from_left(self.f.call((left,))),
+            Either::Right(right) => Choice::from_right(self.g.call((right,)))
+        }
+    }
+}
+
+/// Trait for combining two unary functions with the same output type into one acting on a sum type, where f acts on the left variant and g acts on the right variant.
+/// 
+/// (f ||| g)(Left(x)) = f(x)
+/// 
+/// (f ||| g)(Right(y)) = g(y)
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// Combined with [compose](crate::Compose::compose), this can be used to branch on a [Result](Result) without leaving point-free style.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| x as f32;
+/// let g = |_: &str| f32::NAN;
+/// 
+/// let parse = |x: &'static str| x.parse::<u8>().map_err(|_| x);
+/// 
+/// let h = f.merge(g).compose(parse);
+/// 
+/// assert_eq!(h("1"), 1.0);
+/// assert!(h("x").is_nan());
+/// ```
+#[const_trait]
+pub trait ArrowMerge<G, L, R>: Sized
+{
+    /// Combining two functions acting on each variant of a sum type into one output
+    /// 
+    /// (f ||| g)(Left(x)) = f(x)
+    /// 
+    /// (f ||| g)(Right(y)) = g(y)
+    fn merge(self, with: G) -> ChoiceMerge<Self, G>;
+}
+
+impl<F, G, L, R> const ArrowMerge<G, L, R> for F
+where
+    Self: FnOnce<(L,)>,
+    G: FnOnce<(R,), Output = <Self as FnOnce<(L,)>>::Output>
+{
+    fn merge(self, with: G) -> ChoiceMerge<Self, G>
+    {
+        ChoiceMerge {
+            f: self,
+            g: with
+        }
+    }
+}
+
+/// A struct representing two unary functions acting on each variant of a sum type, collapsing it into one output.
+/// 
+/// See [merge](ArrowMerge::merge).
+#[derive(Clone, Copy, Debug)]
+pub struct ChoiceMerge<F, G>
+{
+    f: F,
+    g: G
+}
+
+impl<F, G, C> FnOnce<(C,)> for ChoiceMerge<F, G>
+where
+    C: Choice,
+    F: FnOnce<(C::Left,)>,
+    G: FnOnce<(C::Right,), Output = F::Output>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => self.f.call_once((left,)),
+            Either::Right(right) => self.g.call_once((right,))
+        }
+    }
+}
+
+impl<F, G, C> FnMut<(C,)> for ChoiceMerge<F, G>
+where
+    C: Choice,
+    F: FnMut<(C::Left,)>,
+    G: FnMut<(C::Right,), Output = F::Output>
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => self.f.call_mut((left,)),
+            Either::Right(right) => self.g.call_mut((right,))
+        }
+    }
+}
+
+impl<F, G, C> Fn<(C,)> for ChoiceMerge<F, G>
+where
+    C: Choice,
+    F: Fn<(C::Left,)>,
+    G: Fn<(C::Right,), Output = F::Output>
+{
+    extern "rust-call" fn call(&self, (x,): (C,)) -> Self::Output
+    {
+        match x.into_either()
+        {
+            Either::Left(left) => self.f.call((left,)),
+            Either::Right(right) => self.g.call((right,))
+        }
+    }
+}
\ No newline at end of file
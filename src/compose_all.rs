@@ -0,0 +1,258 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, TupleLength};
+
+use tuple_split::{TupleSplit, SplitInto};
+
+/// Trait for a tuple of argument-lists, which may be concatenated into a single argument-list and split back up again.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let args: (u8, f32, bool) = (1, 1.0, true);
+/// let (a, b): ((u8, f32), (bool,)) = <((u8, f32), (bool,)) as ConcatArgs>::split_args(args);
+/// 
+/// assert_eq!((a, b), ((1, 1.0), (true,)));
+/// ```
+pub trait ConcatArgs: Tuple
+{
+    /// The concatenation of all the argument-lists.
+    type Type: Tuple;
+
+    /// Splits a concatenated argument-list into each of its argument-lists.
+    fn split_args(args: Self::Type) -> Self;
+}
+
+impl<X> ConcatArgs for (X,)
+where
+    X: Tuple
+{
+    type Type = X;
+
+    fn split_args(args: Self::Type) -> Self
+    {
+        (args,)
+    }
+}
+
+macro_rules! impl_concat_args {
+    (($x0:ident, $v0:ident), ($x1:ident, $v1:ident) $(, ($x:ident, $v:ident))*) => {
+        impl<$x0, $x1, $($x,)*> ConcatArgs for ($x0, $x1, $($x,)*)
+        where
+            $x0: Tuple,
+            ($x1, $($x,)*): ConcatArgs,
+            ($x0, <($x1, $($x,)*) as ConcatArgs>::Type): TupleConcat<$x0, <($x1, $($x,)*) as ConcatArgs>::Type>,
+            ConcatTuples<$x0, <($x1, $($x,)*) as ConcatArgs>::Type>: Tuple + SplitInto<$x0, <($x1, $($x,)*) as ConcatArgs>::Type>,
+            [(); <$x0 as TupleLength>::LENGTH]:
+        {
+            type Type = ConcatTuples<$x0, <($x1, $($x,)*) as ConcatArgs>::Type>;
+
+            fn split_args(args: Self::Type) -> Self
+            {
+                let ($v0, rest): ($x0, <($x1, $($x,)*) as ConcatArgs>::Type) = args.split_tuple();
+                let ($v1, $($v,)*) = <($x1, $($x,)*) as ConcatArgs>::split_args(rest);
+                ($v0, $v1, $($v,)*)
+            }
+        }
+
+        impl_concat_args!(($x1, $v1) $(, ($x, $v))*);
+    };
+    (($x0:ident, $v0:ident)) => {};
+}
+
+impl_concat_args!(
+    (X1, x1),
+    (X2, x2),
+    (X3, x3),
+    (X4, x4),
+    (X5, x5),
+    (X6, x6)
+);
+
+/// Trait for composing a function with a tuple of functions, where the output of each function is passed into the corresponding argument of g.
+/// 
+/// h(x₁, x₂, ...) = g ∘ (f₁, f₂, ...) = g(f₁(x₁), f₂(x₂), ...)
+/// 
+/// The arguments of each function being composed with are concatenated, in order, into the argument-list of the composition.
+/// 
+/// All operands must implement FnOnce. If all of them implement FnMut or Fn, the resulting composition will also implement these traits.
+/// 
+/// g must have exactly as many arguments as there are functions being composed with, and there may be up to 6 functions.
+/// 
+/// This is equivalent to composing g with each function one by one, but results in a single flat [FanInComposition](FanInComposition).
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ (f, f)
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ (f, f) :: u8 -> u8 -> f32
+/// let g = |x: f32, y: f32| x + y;
+/// let f = |x: u8| x as f32;
+/// 
+/// let gff = g.compose_all((f, f));
+/// 
+/// let x = 1;
+/// let y = 1;
+/// 
+/// assert_eq!(gff(x, y), g(f(x), f(y)));
+/// 
+/// // same as composing with each of them separately
+/// assert_eq!(gff(x, y), g.compose(f).compose(f)(x, y));
+/// ```
+#[const_trait]
+pub trait ComposeAll<FS, XG, XFS>: Sized
+{
+    /// Composing a function with a tuple of functions
+    /// 
+    /// h(x₁, x₂, ...) = g ∘ (f₁, f₂, ...) = g(f₁(x₁), f₂(x₂), ...)
+    fn compose_all(self, with: FS) -> FanInComposition<Self, FS, XG, XFS>;
+}
+
+/// A struct representing a function composed with a tuple of functions, one for each of its arguments.
+/// 
+/// When calling the composition as a function, the arguments of each function being composed with are given in order.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ (f₁, f₂, f₃)
+/// // where
+/// // g :: f32 -> f32 -> bool -> f32
+/// // f₁ :: u8 -> u8 -> f32
+/// // f₂ :: () -> f32
+/// // f₃ :: i8 -> bool
+/// // g ∘ (f₁, f₂, f₃) :: u8 -> u8 -> i8 -> f32
+/// let g = |x: f32, y: f32, z: bool| if z {x} else {y};
+/// let f1 = |x: u8, y: u8| (x*y) as f32;
+/// let f2 = || 0.5;
+/// let f3 = |x: i8| x.is_positive();
+/// 
+/// let gf = g.compose_all((f1, f2, f3));
+/// 
+/// assert_eq!(gf(2, 3, 1), 6.0);
+/// assert_eq!(gf(2, 3, -1), 0.5);
+/// ```
+/// 
+/// The composition only implements FnMut or Fn if all of its functions do.
+/// 
+/// ```rust,compile_fail
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// fn require_fn(_: impl Fn(u8, u8) -> f32) {}
+/// 
+/// let mut count = 0;
+/// 
+/// let g = |x: f32, y: f32| x + y;
+/// let f1 = |x: u8| x as f32;
+/// let f2 = |x: u8| {count += 1; x as f32};
+/// 
+/// // f2 is only FnMut
+/// require_fn(g.compose_all((f1, f2)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FanInComposition<G, FS, XG, XFS>
+{
+    g: G,
+    fs: FS,
+    phantom: PhantomData<(XG, XFS)>,
+}
+
+macro_rules! impl_compose_all {
+    (($f0:ident, $g0:ident, $x0:ident, $v0:ident, $y0:ident) $(, ($f:ident, $g:ident, $x:ident, $v:ident, $y:ident))*) => {
+        impl<G, $f0, $($f,)* $x0, $($x,)*> const ComposeAll<($f0, $($f,)*), ($f0::Output, $($f::Output,)*), ($x0, $($x,)*)> for G
+        where
+            Self: FnOnce<($f0::Output, $($f::Output,)*)>,
+            $x0: Tuple,
+            $($x: Tuple,)*
+            $f0: FnOnce<$x0>,
+            $($f: FnOnce<$x>,)*
+            ($x0, $($x,)*): ConcatArgs,
+            FanInComposition<Self, ($f0, $($f,)*), ($f0::Output, $($f::Output,)*), ($x0, $($x,)*)>: FnOnce<<($x0, $($x,)*) as ConcatArgs>::Type>
+        {
+            fn compose_all(self, with: ($f0, $($f,)*)) -> FanInComposition<Self, ($f0, $($f,)*), ($f0::Output, $($f::Output,)*), ($x0, $($x,)*)>
+            {
+                FanInComposition {
+                    g: self,
+                    fs: with,
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)*> FnOnce<<($x0, $($x,)*) as ConcatArgs>::Type> for FanInComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*)>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: FnOnce<($y0, $($y,)*)>,
+            $f0: FnOnce<$x0, Output = $y0>,
+            $($f: FnOnce<$x, Output = $y>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            type Output = <G as FnOnce<($y0, $($y,)*)>>::Output;
+
+            extern "rust-call" fn call_once(self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = self.fs;
+                self.g.call_once(($g0.call_once($v0), $($g.call_once($v),)*))
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)*> FnMut<<($x0, $($x,)*) as ConcatArgs>::Type> for FanInComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*)>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: FnMut<($y0, $($y,)*)>,
+            $f0: FnMut<$x0, Output = $y0>,
+            $($f: FnMut<$x, Output = $y>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            extern "rust-call" fn call_mut(&mut self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = &mut self.fs;
+                self.g.call_mut(($g0.call_mut($v0), $($g.call_mut($v),)*))
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)*> Fn<<($x0, $($x,)*) as ConcatArgs>::Type> for FanInComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*)>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: Fn<($y0, $($y,)*)>,
+            $f0: Fn<$x0, Output = $y0>,
+            $($f: Fn<$x, Output = $y>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            extern "rust-call" fn call(&self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = &self.fs;
+                self.g.call(($g0.call($v0), $($g.call($v),)*))
+            }
+        }
+
+        impl_compose_all!($(($f, $g, $x, $v, $y)),*);
+    };
+    () => {};
+}
+
+impl_compose_all!(
+    (F1, f1, X1, x1, Y1),
+    (F2, f2, X2, x2, Y2),
+    (F3, f3, X3, x3, Y3),
+    (F4, f4, X4, x4, Y4),
+    (F5, f5, X5, x5, Y5),
+    (F6, f6, X6, x6, Y6)
+);
\ No newline at end of file
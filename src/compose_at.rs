@@ -0,0 +1,174 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength, TupleUnprepend, Head, Tail};
+
+use tuple_split::{TupleSplit, SplitInto, Left, Right};
+
+/// The leftover arguments of g after removing the argument at index N.
+pub type SkipAt<XG, const N: usize> = ConcatTuples<Left<XG, N>, Tail<Right<XG, N>>>;
+
+/// The type of the argument at index N.
+pub type ArgAt<XG, const N: usize> = Head<Right<XG, N>>;
+
+/// Trait for composing two functions, where the output of f is passed into an arbitrary argument of g.
+/// 
+/// h(..., x, ...) = g ∘ₙ f = g(..., f(x), ...)
+/// 
+/// The index of the argument is specified as the const generic N. [compose_at::<0>](ComposeAt::compose_at) is equivalent to [compose](crate::Compose::compose).
+/// 
+/// The leftover arguments of g keep their order, then the arguments of f follow at the end of the argument-list.
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting composition will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘₁ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘₁ f :: f32 -> u8 -> f32
+/// let g = |x: f32, y: f32| x - y;
+/// let f = |x: u8| x as f32;
+/// 
+/// let gf = g.compose_at::<1>(f);
+/// 
+/// let x = 1.0;
+/// let y = 2;
+/// 
+/// assert_eq!(gf(x, y), g(x, f(y)));
+/// ```
+#[const_trait]
+pub trait ComposeAt<F, XG, XF>: Sized
+{
+    /// Composing two functions at argument N of g
+    /// 
+    /// h(..., x, ...) = g ∘ₙ f = g(..., f(x), ...)
+    fn compose_at<const N: usize>(self, with: F) -> CompositionAt<Self, F, XG, XF, N>;
+}
+
+impl<G, F, XG, XF> const ComposeAt<F, XG, XF> for G
+where
+    XG: Tuple,
+    XF: Tuple,
+    Self: FnOnce<XG>,
+    F: FnOnce<XF>
+{
+    fn compose_at<const N: usize>(self, with: F) -> CompositionAt<Self, F, XG, XF, N>
+    {
+        CompositionAt {
+            g: self,
+            f: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function composed with another at a given argument index N.
+/// 
+/// When calling the composition as a function, the leftover arguments of the composition function come first, in their original order, then the arguments of the function being composed with.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘₂ f
+/// // where
+/// // g :: u8 -> u8 -> f32 -> f32
+/// // f :: bool -> f32
+/// // g ∘₂ f :: u8 -> u8 -> bool -> f32
+/// let g = |x: u8, y: u8, z: f32| (x + y) as f32*z;
+/// let f = |x: bool| if x {1.0} else {-1.0};
+/// 
+/// let gf = g.compose_at::<2>(f);
+/// 
+/// let x = 1;
+/// let y = 2;
+/// let z = false;
+/// 
+/// assert_eq!(gf(x, y, z), g(x, y, f(z)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CompositionAt<G, F, XG, XF, const N: usize>
+{
+    g: G,
+    f: F,
+    phantom: PhantomData<(XG, XF)>,
+}
+
+impl<G, F, XG, XF, const N: usize> FnOnce<ConcatTuples<SkipAt<XG, N>, XF>> for CompositionAt<G, F, XG, XF, N>
+where
+    XG: Tuple + TupleSplit<N>,
+    XF: Tuple,
+    Right<XG, N>: TupleUnprepend<Right<XG, N>>,
+    G: FnOnce<XG>,
+    F: FnOnce<XF, Output = ArgAt<XG, N>>,
+    (Left<XG, N>, Tail<Right<XG, N>>): TupleConcat<Left<XG, N>, Tail<Right<XG, N>>>,
+    SkipAt<XG, N>: Tuple + SplitInto<Left<XG, N>, Tail<Right<XG, N>>>,
+    (SkipAt<XG, N>, XF): TupleConcat<SkipAt<XG, N>, XF>,
+    ConcatTuples<SkipAt<XG, N>, XF>: Tuple + SplitInto<SkipAt<XG, N>, XF>,
+    [(); <Left<XG, N> as TupleLength>::LENGTH]:,
+    [(); <SkipAt<XG, N> as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<Right<XG, N>>): TupleConcat<(F::Output,), Tail<Right<XG, N>>, Type = Right<XG, N>>,
+    (Left<XG, N>, Right<XG, N>): TupleConcat<Left<XG, N>, Right<XG, N>, Type = XG>
+{
+    type Output = <G as FnOnce<XG>>::Output;
+
+    extern "rust-call" fn call_once(self, args: ConcatTuples<SkipAt<XG, N>, XF>) -> Self::Output
+    {
+        let (rest, right): (SkipAt<XG, N>, XF) = args.split_tuple();
+        let (before, after): (Left<XG, N>, Tail<Right<XG, N>>) = rest.split_tuple();
+        self.g.call_once(concat_tuples(before, concat_tuples((self.f.call_once(right),), after)))
+    }
+}
+
+impl<G, F, XG, XF, const N: usize> FnMut<ConcatTuples<SkipAt<XG, N>, XF>> for CompositionAt<G, F, XG, XF, N>
+where
+    XG: Tuple + TupleSplit<N>,
+    XF: Tuple,
+    Right<XG, N>: TupleUnprepend<Right<XG, N>>,
+    G: FnMut<XG>,
+    F: FnMut<XF, Output = ArgAt<XG, N>>,
+    (Left<XG, N>, Tail<Right<XG, N>>): TupleConcat<Left<XG, N>, Tail<Right<XG, N>>>,
+    SkipAt<XG, N>: Tuple + SplitInto<Left<XG, N>, Tail<Right<XG, N>>>,
+    (SkipAt<XG, N>, XF): TupleConcat<SkipAt<XG, N>, XF>,
+    ConcatTuples<SkipAt<XG, N>, XF>: Tuple + SplitInto<SkipAt<XG, N>, XF>,
+    [(); <Left<XG, N> as TupleLength>::LENGTH]:,
+    [(); <SkipAt<XG, N> as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<Right<XG, N>>): TupleConcat<(F::Output,), Tail<Right<XG, N>>, Type = Right<XG, N>>,
+    (Left<XG, N>, Right<XG, N>): TupleConcat<Left<XG, N>, Right<XG, N>, Type = XG>
+{
+    extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<SkipAt<XG, N>, XF>) -> Self::Output
+    {
+        let (rest, right): (SkipAt<XG, N>, XF) = args.split_tuple();
+        let (before, after): (Left<XG, N>, Tail<Right<XG, N>>) = rest.split_tuple();
+        self.g.call_mut(concat_tuples(before, concat_tuples((self.f.call_mut(right),), after)))
+    }
+}
+
+impl<G, F, XG, XF, const N: usize> Fn<ConcatTuples<SkipAt<XG, N>, XF>> for CompositionAt<G, F, XG, XF, N>
+where
+    XG: Tuple + TupleSplit<N>,
+    XF: Tuple,
+    Right<XG, N>: TupleUnprepend<Right<XG, N>>,
+    G: Fn<XG>,
+    F: Fn<XF, Output = ArgAt<XG, N>>,
+    (Left<XG, N>, Tail<Right<XG, N>>): TupleConcat<Left<XG, N>, Tail<Right<XG, N>>>,
+    SkipAt<XG, N>: Tuple + SplitInto<Left<XG, N>, Tail<Right<XG, N>>>,
+    (SkipAt<XG, N>, XF): TupleConcat<SkipAt<XG, N>, XF>,
+    ConcatTuples<SkipAt<XG, N>, XF>: Tuple + SplitInto<SkipAt<XG, N>, XF>,
+    [(); <Left<XG, N> as TupleLength>::LENGTH]:,
+    [(); <SkipAt<XG, N> as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<Right<XG, N>>): TupleConcat<(F::Output,), Tail<Right<XG, N>>, Type = Right<XG, N>>,
+    (Left<XG, N>, Right<XG, N>): TupleConcat<Left<XG, N>, Right<XG, N>, Type = XG>
+{
+    extern "rust-call" fn call(&self, args: ConcatTuples<SkipAt<XG, N>, XF>) -> Self::Output
+    {
+        let (rest, right): (SkipAt<XG, N>, XF) = args.split_tuple();
+        let (before, after): (Left<XG, N>, Tail<Right<XG, N>>) = rest.split_tuple();
+        self.g.call(concat_tuples(before, concat_tuples((self.f.call(right),), after)))
+    }
+}
\ No newline at end of file
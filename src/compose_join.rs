@@ -0,0 +1,358 @@
+use std::future::Future;
+use std::marker::{Tuple, PhantomData};
+use std::mem;
+use std::ops::AsyncFnOnce;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::ConcatArgs;
+
+/// Helper type for a future being joined, which keeps its output once it is done.
+#[doc(hidden)]
+pub enum MaybeDone<F>
+where
+    F: Future
+{
+    Pending(F),
+    Done(F::Output),
+    Taken
+}
+
+impl<F> MaybeDone<F>
+where
+    F: Future
+{
+    pub(crate) fn poll_done(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool
+    {
+        // SAFETY: only the pending future is pinned, and it is only ever dropped in place
+        let this = unsafe {self.get_unchecked_mut()};
+        if let MaybeDone::Pending(future) = this
+        {
+            match unsafe {Pin::new_unchecked(future)}.poll(cx)
+            {
+                Poll::Ready(y) => *this = MaybeDone::Done(y),
+                Poll::Pending => return false
+            }
+        }
+        true
+    }
+
+    pub(crate) fn take_output(self: Pin<&mut Self>) -> F::Output
+    {
+        // SAFETY: only the output is moved out, once the pinned future is already gone
+        let this = unsafe {self.get_unchecked_mut()};
+        match this
+        {
+            MaybeDone::Pending(_) => panic!("output taken before the future completed"),
+            MaybeDone::Taken => panic!("output taken twice"),
+            MaybeDone::Done(_) => match mem::replace(this, MaybeDone::Taken)
+            {
+                MaybeDone::Done(y) => y,
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+/// Helper trait for a tuple of futures being driven concurrently, until all of them are done.
+#[doc(hidden)]
+pub trait JoinAll
+{
+    type Output: Tuple;
+
+    fn poll_join(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+}
+
+/// Trait for composing an asynchronous function with a tuple of asynchronous functions, which are driven concurrently, where the output of each function is passed into the corresponding argument of g.
+/// 
+/// h(x₁, x₂, ...) = g ∘ (f₁, f₂, ...) = g(join(f₁(x₁), f₂(x₂), ...).await).await
+/// 
+/// All the futures of f₁, f₂, ... are polled in turn, and once all of them are done, g is called with their outputs, and then awaited.
+/// The arguments of each function being composed with are concatenated, in order, into the argument-list of the composition, just like with [compose_all](crate::ComposeAll::compose_all).
+/// 
+/// All operands must implement [AsyncFnOnce](std::ops::AsyncFnOnce). As with [async_compose](crate::AsyncCompose::async_compose), the composition also implements FnMut or Fn if each f is a function returning a future implementing FnMut or Fn, and g implements Clone.
+/// 
+/// g must have exactly as many arguments as there are functions being composed with, and there may be up to 6 functions.
+/// 
+/// Stages may be given a deadline using [timeout](crate::Timeout::timeout).
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::RefCell;
+/// use std::future::{Future, ready};
+/// use std::pin::{Pin, pin};
+/// use std::task::{Context, Poll, Waker};
+/// 
+/// use currycompose::*;
+/// 
+/// // a minimal executor
+/// fn block_on<F: Future>(future: F) -> F::Output
+/// {
+///     let mut future = pin!(future);
+///     let mut cx = Context::from_waker(Waker::noop());
+///     loop
+///     {
+///         if let Poll::Ready(y) = future.as_mut().poll(&mut cx)
+///         {
+///             return y
+///         }
+///     }
+/// }
+/// 
+/// // a future which is pending n times
+/// struct Yield(usize);
+/// 
+/// impl Future for Yield
+/// {
+///     type Output = ();
+/// 
+///     fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()>
+///     {
+///         if self.0 == 0
+///         {
+///             return Poll::Ready(())
+///         }
+///         self.0 -= 1;
+///         Poll::Pending
+///     }
+/// }
+/// 
+/// let log = RefCell::new(vec![]);
+/// 
+/// let fetch = |name: &'static str, n: usize| {
+///     let log = &log;
+///     async move {
+///         log.borrow_mut().push(name);
+///         Yield(n).await;
+///         log.borrow_mut().push(name);
+///         n
+///     }
+/// };
+/// 
+/// // g ∘ (f₁, f₂)
+/// // where
+/// // g :: usize -> usize -> impl Future<Output = usize>
+/// // f₁ :: usize -> impl Future<Output = usize>
+/// // f₂ :: usize -> impl Future<Output = usize>
+/// // g ∘ (f₁, f₂) :: usize -> usize -> impl Future<Output = usize>
+/// let g = async |x: usize, y: usize| x + y;
+/// let f1 = |n: usize| fetch("slow", n);
+/// let f2 = |n: usize| fetch("fast", n);
+/// 
+/// let gff = g.compose_join((f1, f2));
+/// 
+/// assert_eq!(block_on(gff(3, 1)), 4);
+/// 
+/// // both stages were started before either of them finished
+/// assert_eq!(log.take(), ["slow", "fast", "fast", "slow"]);
+/// ```
+#[const_trait]
+pub trait ComposeJoin<FS, XG, XFS>: Sized
+{
+    /// Composing an asynchronous function with a tuple of asynchronous functions, which are driven concurrently
+    /// 
+    /// h(x₁, x₂, ...) = g ∘ (f₁, f₂, ...) = g(join(f₁(x₁), f₂(x₂), ...).await).await
+    fn compose_join(self, with: FS) -> JoinComposition<Self, FS, XG, XFS>;
+}
+
+/// A struct representing an asynchronous function composed with a tuple of asynchronous functions, one for each of its arguments, which are driven concurrently.
+/// 
+/// See [compose_join](ComposeJoin::compose_join).
+#[derive(Clone, Copy, Debug)]
+pub struct JoinComposition<G, FS, XG, XFS>
+{
+    g: G,
+    fs: FS,
+    phantom: PhantomData<(XG, XFS)>
+}
+
+enum JoinedState<FS, FG>
+{
+    First(FS),
+    Second(FG)
+}
+
+/// The future returned by a [join composition](JoinComposition), which drives all of f₁, f₂, ... concurrently, then calls and awaits g.
+/// 
+/// FS is a tuple of the futures returned by f₁, f₂, ...
+pub struct Joined<G, XG, FS>
+where
+    XG: Tuple,
+    G: AsyncFnOnce<XG>
+{
+    g: Option<G>,
+    state: JoinedState<FS, G::CallOnceFuture>
+}
+
+impl<G, XG, FS> Future for Joined<G, XG, FS>
+where
+    XG: Tuple,
+    G: AsyncFnOnce<XG>,
+    FS: JoinAll<Output = XG>
+{
+    type Output = G::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        // SAFETY: the futures are only ever dropped in place, while g is never pinned
+        let this = unsafe {self.get_unchecked_mut()};
+        loop
+        {
+            match &mut this.state
+            {
+                JoinedState::First(futures) => {
+                    let x = match unsafe {Pin::new_unchecked(futures)}.poll_join(cx)
+                    {
+                        Poll::Ready(x) => x,
+                        Poll::Pending => return Poll::Pending
+                    };
+                    let g = this.g.take().unwrap();
+                    this.state = JoinedState::Second(g.async_call_once(x));
+                },
+                JoinedState::Second(future) => return unsafe {Pin::new_unchecked(future)}.poll(cx)
+            }
+        }
+    }
+}
+
+macro_rules! impl_compose_join {
+    (($f0:ident, $g0:ident, $x0:ident, $v0:ident, $y0:ident) $(, ($f:ident, $g:ident, $x:ident, $v:ident, $y:ident))*) => {
+        impl<$f0, $($f,)*> JoinAll for (MaybeDone<$f0>, $(MaybeDone<$f>,)*)
+        where
+            $f0: Future,
+            $($f: Future,)*
+        {
+            type Output = ($f0::Output, $($f::Output,)*);
+
+            fn poll_join(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+            {
+                // SAFETY: each future is pinned along with the tuple, and never moved out of it
+                let ($g0, $($g,)*) = unsafe {self.get_unchecked_mut()};
+                let (mut $g0, $(mut $g,)*) = unsafe {(Pin::new_unchecked($g0), $(Pin::new_unchecked($g),)*)};
+                let done = $g0.as_mut().poll_done(cx);
+                $(let done = $g.as_mut().poll_done(cx) & done;)*
+                if !done
+                {
+                    return Poll::Pending
+                }
+                Poll::Ready(($g0.take_output(), $($g.take_output(),)*))
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)*> const ComposeJoin<($f0, $($f,)*), (<$f0 as AsyncFnOnce<$x0>>::Output, $(<$f as AsyncFnOnce<$x>>::Output,)*), ($x0, $($x,)*)> for G
+        where
+            Self: AsyncFnOnce<(<$f0 as AsyncFnOnce<$x0>>::Output, $(<$f as AsyncFnOnce<$x>>::Output,)*)>,
+            $x0: Tuple,
+            $($x: Tuple,)*
+            $f0: AsyncFnOnce<$x0>,
+            $($f: AsyncFnOnce<$x>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            fn compose_join(self, with: ($f0, $($f,)*)) -> JoinComposition<Self, ($f0, $($f,)*), (<$f0 as AsyncFnOnce<$x0>>::Output, $(<$f as AsyncFnOnce<$x>>::Output,)*), ($x0, $($x,)*)>
+            {
+                JoinComposition {
+                    g: self,
+                    fs: with,
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)*> FnOnce<<($x0, $($x,)*) as ConcatArgs>::Type> for JoinComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*)>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: AsyncFnOnce<($y0, $($y,)*)>,
+            $f0: AsyncFnOnce<$x0, Output = $y0>,
+            $($f: AsyncFnOnce<$x, Output = $y>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            type Output = Joined<G, ($y0, $($y,)*), (MaybeDone<$f0::CallOnceFuture>, $(MaybeDone<$f::CallOnceFuture>,)*)>;
+
+            extern "rust-call" fn call_once(self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = self.fs;
+                Joined {
+                    g: Some(self.g),
+                    state: JoinedState::First((MaybeDone::Pending($g0.async_call_once($v0)), $(MaybeDone::Pending($g.async_call_once($v)),)*))
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)*> FnMut<<($x0, $($x,)*) as ConcatArgs>::Type> for JoinComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*)>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: AsyncFnOnce<($y0, $($y,)*)> + Clone,
+            $f0: FnMut<$x0> + AsyncFnOnce<$x0, Output = $y0, CallOnceFuture = <$f0 as FnOnce<$x0>>::Output>,
+            $($f: FnMut<$x> + AsyncFnOnce<$x, Output = $y, CallOnceFuture = <$f as FnOnce<$x>>::Output>,)*
+            <$f0 as FnOnce<$x0>>::Output: Future,
+            $(<$f as FnOnce<$x>>::Output: Future,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            extern "rust-call" fn call_mut(&mut self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = &mut self.fs;
+                Joined {
+                    g: Some(self.g.clone()),
+                    state: JoinedState::First((MaybeDone::Pending($g0.call_mut($v0)), $(MaybeDone::Pending($g.call_mut($v)),)*))
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)*> Fn<<($x0, $($x,)*) as ConcatArgs>::Type> for JoinComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*)>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: AsyncFnOnce<($y0, $($y,)*)> + Clone,
+            $f0: Fn<$x0> + AsyncFnOnce<$x0, Output = $y0, CallOnceFuture = <$f0 as FnOnce<$x0>>::Output>,
+            $($f: Fn<$x> + AsyncFnOnce<$x, Output = $y, CallOnceFuture = <$f as FnOnce<$x>>::Output>,)*
+            <$f0 as FnOnce<$x0>>::Output: Future,
+            $(<$f as FnOnce<$x>>::Output: Future,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            extern "rust-call" fn call(&self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = &self.fs;
+                Joined {
+                    g: Some(self.g.clone()),
+                    state: JoinedState::First((MaybeDone::Pending($g0.call($v0)), $(MaybeDone::Pending($g.call($v)),)*))
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)*> AsyncFnOnce<<($x0, $($x,)*) as ConcatArgs>::Type> for JoinComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*)>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: AsyncFnOnce<($y0, $($y,)*)>,
+            $f0: AsyncFnOnce<$x0, Output = $y0>,
+            $($f: AsyncFnOnce<$x, Output = $y>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            type Output = G::Output;
+            type CallOnceFuture = Joined<G, ($y0, $($y,)*), (MaybeDone<$f0::CallOnceFuture>, $(MaybeDone<$f::CallOnceFuture>,)*)>;
+
+            extern "rust-call" fn async_call_once(self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::CallOnceFuture
+            {
+                self.call_once(args)
+            }
+        }
+
+        impl_compose_join!($(($f, $g, $x, $v, $y)),*);
+    };
+    () => {};
+}
+
+impl_compose_join!(
+    (F1, f1, X1, x1, Y1),
+    (F2, f2, X2, x2, Y2),
+    (F3, f3, X3, x3, Y3),
+    (F4, f4, X4, x4, Y4),
+    (F5, f5, X5, x5, Y5),
+    (F6, f6, X6, x6, Y6)
+);
\ No newline at end of file
@@ -0,0 +1,163 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength};
+
+use tuple_split::{TupleSplit, SplitInto};
+
+/// Trait for composing two functions, where f returns a tuple which is spread into the leading arguments of g.
+/// 
+/// Non-currying composition:
+/// h(x) = g ∘ f = g(f(x)...)
+/// 
+/// Currying composition:
+/// h(..., x) = g ∘ f = g(f(x)..., ...)
+/// 
+/// When currying, arguments of the function being curried with (f) is moved to the end of the argument-list, just like with [compose](crate::Compose::compose).
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting composition will also implement these traits.
+/// 
+/// f must return a tuple, whose elements equal the types of the leading arguments of g.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: u8 -> u8 -> f32 -> f32
+/// // f :: u16 -> (u8, u8)
+/// // g ∘ f :: f32 -> u16 -> f32
+/// let g = |x: u8, y: u8, z: f32| (x as f32 + y as f32)*z;
+/// let f = |x: u16| ((x >> 8) as u8, x as u8);
+/// 
+/// let gf = g.compose_splat(f);
+/// 
+/// let x = 0x0102;
+/// let z = 0.5;
+/// 
+/// let (a, b) = f(x);
+/// 
+/// assert_eq!(gf(z, x), g(a, b, z));
+/// ```
+#[const_trait]
+pub trait ComposeSplat<F, XG, XF, XR>: Sized
+{
+    /// Composing two functions, spreading the output of f into the arguments of g
+    /// 
+    /// h(x) = g ∘ f = g(f(x)...)
+    fn compose_splat(self, with: F) -> SplatComposition<Self, F, XG, XF, XR>;
+}
+
+impl<G, F, XG, XF, XR, Y> const ComposeSplat<F, XG, XF, XR> for G
+where
+    XG: Tuple + TupleSplit<{<Y as TupleLength>::LENGTH}, Left = Y, Right = XR>,
+    XF: Tuple,
+    Y: Tuple + TupleLength,
+    Self: FnOnce<XG>,
+    F: FnOnce<XF, Output = Y>,
+    (XR, XF): TupleConcat<XR, XF>,
+    ConcatTuples<XR, XF>: Tuple,
+    SplatComposition<Self, F, XG, XF, XR>: FnOnce<ConcatTuples<XR, XF>>
+{
+    fn compose_splat(self, with: F) -> SplatComposition<Self, F, XG, XF, XR>
+    {
+        SplatComposition {
+            g: self,
+            f: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function composed with another returning a tuple, which is spread into the leading arguments of the composition function.
+/// 
+/// When calling the composition as a function, the leftover arguments of the composition function come first (if curried), then the arguments of the function being composed with.
+/// 
+/// XR is the tuple of leftover arguments of the composition function.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: f32 -> (f32, f32)
+/// // g ∘ f :: f32 -> f32
+/// let g = |x: f32, y: f32| x*y;
+/// let f = |x: f32| (x + 1.0, x - 1.0);
+/// 
+/// let gf = g.compose_splat(f);
+/// 
+/// let x = 3.0;
+/// 
+/// assert_eq!(gf(x), 8.0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SplatComposition<G, F, XG, XF, XR>
+{
+    g: G,
+    f: F,
+    phantom: PhantomData<(XG, XF, XR)>,
+}
+
+impl<G, F, XG, XF, XR, Y> FnOnce<ConcatTuples<XR, XF>> for SplatComposition<G, F, XG, XF, XR>
+where
+    XG: Tuple + TupleSplit<{<Y as TupleLength>::LENGTH}, Left = Y, Right = XR>,
+    XF: Tuple,
+    Y: Tuple + TupleLength,
+    G: FnOnce<XG>,
+    F: FnOnce<XF, Output = Y>,
+    (XR, XF): TupleConcat<XR, XF>,
+    ConcatTuples<XR, XF>: Tuple + SplitInto<XR, XF>,
+    [(); <XR as TupleLength>::LENGTH]:,
+    (Y, XR): TupleConcat<Y, XR, Type = XG>
+{
+    type Output = <G as FnOnce<XG>>::Output;
+
+    extern "rust-call" fn call_once(self, args: ConcatTuples<XR, XF>) -> Self::Output
+    {
+        let (left, right): (XR, XF) = args.split_tuple();
+        self.g.call_once(concat_tuples(self.f.call_once(right), left))
+    }
+}
+
+impl<G, F, XG, XF, XR, Y> FnMut<ConcatTuples<XR, XF>> for SplatComposition<G, F, XG, XF, XR>
+where
+    XG: Tuple + TupleSplit<{<Y as TupleLength>::LENGTH}, Left = Y, Right = XR>,
+    XF: Tuple,
+    Y: Tuple + TupleLength,
+    G: FnMut<XG>,
+    F: FnMut<XF, Output = Y>,
+    (XR, XF): TupleConcat<XR, XF>,
+    ConcatTuples<XR, XF>: Tuple + SplitInto<XR, XF>,
+    [(); <XR as TupleLength>::LENGTH]:,
+    (Y, XR): TupleConcat<Y, XR, Type = XG>
+{
+    extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<XR, XF>) -> Self::Output
+    {
+        let (left, right) = args.split_tuple();
+        self.g.call_mut(concat_tuples(self.f.call_mut(right), left))
+    }
+}
+
+impl<G, F, XG, XF, XR, Y> Fn<ConcatTuples<XR, XF>> for SplatComposition<G, F, XG, XF, XR>
+where
+    XG: Tuple + TupleSplit<{<Y as TupleLength>::LENGTH}, Left = Y, Right = XR>,
+    XF: Tuple,
+    Y: Tuple + TupleLength,
+    G: Fn<XG>,
+    F: Fn<XF, Output = Y>,
+    (XR, XF): TupleConcat<XR, XF>,
+    ConcatTuples<XR, XF>: Tuple + SplitInto<XR, XF>,
+    [(); <XR as TupleLength>::LENGTH]:,
+    (Y, XR): TupleConcat<Y, XR, Type = XG>
+{
+    extern "rust-call" fn call(&self, args: ConcatTuples<XR, XF>) -> Self::Output
+    {
+        let (left, right) = args.split_tuple();
+        self.g.call(concat_tuples(self.f.call(right), left))
+    }
+}
\ No newline at end of file
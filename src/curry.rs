@@ -0,0 +1,320 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples};
+
+/// Trait for turning a function taking several arguments into a chain of functions taking one argument each.
+/// 
+/// curry(f)(x)(y)(z) = f(x, y, z)
+/// 
+/// Each function in the chain is a [Curried](Curried), holding the arguments given so far. The last one calls the original function.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // f :: u8 -> u8 -> u8 -> u8
+/// let f = |x: u8, y: u8, z: u8| x*100 + y*10 + z;
+/// 
+/// let g = f.curry();
+/// 
+/// assert_eq!(g(1)(2)(3), f(1, 2, 3));
+/// 
+/// // the curried chain can be composed like any other function
+/// let h = |x: bool| x as u8;
+/// 
+/// let gh = g.compose(h);
+/// 
+/// assert_eq!(gh(true)(2)(3), f(1, 2, 3));
+/// ```
+#[const_trait]
+pub trait Curry<X>: Sized
+{
+    /// Currying a function
+    /// 
+    /// curry(f)(x)(y)(z) = f(x, y, z)
+    fn curry(self) -> Curried<Self, X, ()>;
+}
+
+impl<F, X> const Curry<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn curry(self) -> Curried<Self, X, ()>
+    {
+        Curried {
+            f: self,
+            args: (),
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a curried function, which takes its remaining arguments XR one at a time.
+/// 
+/// The arguments given so far are held in the tuple XP.
+/// 
+/// When called through FnOnce, the function and the arguments are moved into the next function in the chain.
+/// When called through FnMut or Fn, they are cloned, unless this is the last argument, in which case only the arguments given so far are cloned.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8| x*10 + y;
+/// 
+/// let g = f.curry();
+/// let g1 = g(1);
+/// 
+/// assert_eq!(g1(2), 12);
+/// assert_eq!(g1(3), 13);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Curried<F, XR, XP>
+{
+    f: F,
+    args: XP,
+    phantom: PhantomData<XR>
+}
+
+impl<F, A, XP> FnOnce<(A,)> for Curried<F, (A,), XP>
+where
+    XP: Tuple,
+    (XP, (A,)): TupleConcat<XP, (A,)>,
+    ConcatTuples<XP, (A,)>: Tuple,
+    F: FnOnce<ConcatTuples<XP, (A,)>>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, (arg,): (A,)) -> Self::Output
+    {
+        self.f.call_once(concat_tuples(self.args, (arg,)))
+    }
+}
+
+impl<F, A, XP> FnMut<(A,)> for Curried<F, (A,), XP>
+where
+    XP: Tuple + Clone,
+    (XP, (A,)): TupleConcat<XP, (A,)>,
+    ConcatTuples<XP, (A,)>: Tuple,
+    F: FnMut<ConcatTuples<XP, (A,)>>
+{
+    extern "rust-call" fn call_mut(&mut self, (arg,): (A,)) -> Self::Output
+    {
+        self.f.call_mut(concat_tuples(self.args.clone(), (arg,)))
+    }
+}
+
+impl<F, A, XP> Fn<(A,)> for Curried<F, (A,), XP>
+where
+    XP: Tuple + Clone,
+    (XP, (A,)): TupleConcat<XP, (A,)>,
+    ConcatTuples<XP, (A,)>: Tuple,
+    F: Fn<ConcatTuples<XP, (A,)>>
+{
+    extern "rust-call" fn call(&self, (arg,): (A,)) -> Self::Output
+    {
+        self.f.call(concat_tuples(self.args.clone(), (arg,)))
+    }
+}
+
+macro_rules! impl_curried {
+    ($a0:ident, $a1:ident $(, $a:ident)*) => {
+        impl<F, $a0, $a1, $($a,)* XP> FnOnce<($a0,)> for Curried<F, ($a0, $a1, $($a,)*), XP>
+        where
+            XP: Tuple,
+            (XP, ($a0,)): TupleConcat<XP, ($a0,)>
+        {
+            type Output = Curried<F, ($a1, $($a,)*), ConcatTuples<XP, ($a0,)>>;
+
+            extern "rust-call" fn call_once(self, (arg,): ($a0,)) -> Self::Output
+            {
+                Curried {
+                    f: self.f,
+                    args: concat_tuples(self.args, (arg,)),
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl<F, $a0, $a1, $($a,)* XP> FnMut<($a0,)> for Curried<F, ($a0, $a1, $($a,)*), XP>
+        where
+            F: Clone,
+            XP: Tuple + Clone,
+            (XP, ($a0,)): TupleConcat<XP, ($a0,)>
+        {
+            extern "rust-call" fn call_mut(&mut self, (arg,): ($a0,)) -> Self::Output
+            {
+                Curried {
+                    f: self.f.clone(),
+                    args: concat_tuples(self.args.clone(), (arg,)),
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl<F, $a0, $a1, $($a,)* XP> Fn<($a0,)> for Curried<F, ($a0, $a1, $($a,)*), XP>
+        where
+            F: Clone,
+            XP: Tuple + Clone,
+            (XP, ($a0,)): TupleConcat<XP, ($a0,)>
+        {
+            extern "rust-call" fn call(&self, (arg,): ($a0,)) -> Self::Output
+            {
+                Curried {
+                    f: self.f.clone(),
+                    args: concat_tuples(self.args.clone(), (arg,)),
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl_curried!($a1 $(, $a)*);
+    };
+    ($a0:ident) => {};
+}
+
+impl_curried!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+
+/// Trait for turning a chain of functions taking one argument each into a function taking all of the arguments at once.
+/// 
+/// uncurry(f)(x, y, z) = f(x)(y)(z)
+/// 
+/// The number of arguments is given by how the uncurried function is called. Only the outermost function needs to implement FnMut or Fn for the uncurried function to implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| move |y: u8| move |z: u8| x*100 + y*10 + z;
+/// 
+/// let g = f.uncurry();
+/// 
+/// assert_eq!(g(1, 2, 3), f(1)(2)(3));
+/// 
+/// // uncurry is the inverse of curry
+/// let h = |x: u8, y: u8| x*10 + y;
+/// 
+/// assert_eq!(h.curry().uncurry()(1, 2), h(1, 2));
+/// ```
+#[const_trait]
+pub trait Uncurry<A>: Sized
+{
+    /// Uncurrying a function
+    /// 
+    /// uncurry(f)(x, y, z) = f(x)(y)(z)
+    fn uncurry(self) -> Uncurried<Self>;
+}
+
+impl<F, A> const Uncurry<A> for F
+where
+    Self: FnOnce<(A,)>
+{
+    fn uncurry(self) -> Uncurried<Self>
+    {
+        Uncurried {
+            f: self
+        }
+    }
+}
+
+/// A struct representing a chain of functions taking one argument each, called with all of the arguments at once.
+/// 
+/// See [uncurry](Uncurry::uncurry).
+#[derive(Clone, Copy, Debug)]
+pub struct Uncurried<F>
+{
+    f: F
+}
+
+/// Helper trait for calling the rest of a chain of curried functions once the first argument has been given.
+#[doc(hidden)]
+pub trait UncurriedRest<X>
+{
+    type Output;
+
+    fn call_uncurried(self, args: X) -> Self::Output;
+}
+
+impl<Y> UncurriedRest<()> for Y
+{
+    type Output = Y;
+
+    fn call_uncurried(self, (): ()) -> Self::Output
+    {
+        self
+    }
+}
+
+macro_rules! impl_uncurried {
+    (($a0:ident, $x0:ident) $(, ($a:ident, $x:ident))*) => {
+        impl<F, $a0, $($a,)*> FnOnce<($a0, $($a,)*)> for Uncurried<F>
+        where
+            F: FnOnce<($a0,)>,
+            F::Output: UncurriedRest<($($a,)*)>
+        {
+            type Output = <F::Output as UncurriedRest<($($a,)*)>>::Output;
+
+            extern "rust-call" fn call_once(self, ($x0, $($x,)*): ($a0, $($a,)*)) -> Self::Output
+            {
+                self.f.call_once(($x0,)).call_uncurried(($($x,)*))
+            }
+        }
+
+        impl<F, $a0, $($a,)*> FnMut<($a0, $($a,)*)> for Uncurried<F>
+        where
+            F: FnMut<($a0,)>,
+            F::Output: UncurriedRest<($($a,)*)>
+        {
+            extern "rust-call" fn call_mut(&mut self, ($x0, $($x,)*): ($a0, $($a,)*)) -> Self::Output
+            {
+                self.f.call_mut(($x0,)).call_uncurried(($($x,)*))
+            }
+        }
+
+        impl<F, $a0, $($a,)*> Fn<($a0, $($a,)*)> for Uncurried<F>
+        where
+            F: Fn<($a0,)>,
+            F::Output: UncurriedRest<($($a,)*)>
+        {
+            extern "rust-call" fn call(&self, ($x0, $($x,)*): ($a0, $($a,)*)) -> Self::Output
+            {
+                self.f.call(($x0,)).call_uncurried(($($x,)*))
+            }
+        }
+
+        impl<F, $a0, $($a,)*> UncurriedRest<($a0, $($a,)*)> for F
+        where
+            F: FnOnce<($a0,)>,
+            F::Output: UncurriedRest<($($a,)*)>
+        {
+            type Output = <F::Output as UncurriedRest<($($a,)*)>>::Output;
+
+            fn call_uncurried(self, ($x0, $($x,)*): ($a0, $($a,)*)) -> Self::Output
+            {
+                self.call_once(($x0,)).call_uncurried(($($x,)*))
+            }
+        }
+
+        impl_uncurried!($(($a, $x)),*);
+    };
+    () => {};
+}
+
+impl_uncurried!(
+    (A1, x1),
+    (A2, x2),
+    (A3, x3),
+    (A4, x4),
+    (A5, x5),
+    (A6, x6),
+    (A7, x7),
+    (A8, x8),
+    (A9, x9),
+    (A10, x10),
+    (A11, x11),
+    (A12, x12)
+);
\ No newline at end of file
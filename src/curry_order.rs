@@ -0,0 +1,156 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength, TupleUnprepend, Head, Tail};
+
+use tuple_split::{TupleSplit, SplitInto};
+
+use crate::Composition;
+
+/// Policy for where the arguments of the function being composed with are placed in the argument-list of a [Composition](crate::Composition).
+pub trait CurryOrder
+{
+
+}
+
+/// The leftover arguments of the composition function come first, then the arguments of the function being composed with.
+/// 
+/// h(..., x) = g ∘ f = g(f(x), ...)
+/// 
+/// This is the default order, used by [compose](crate::Compose::compose).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CurryBack;
+
+/// The arguments of the function being composed with come first, then the leftover arguments of the composition function.
+/// 
+/// h(x, ...) = g ∘ f = g(f(x), ...)
+/// 
+/// This is the order used by [compose_front](ComposeFront::compose_front).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CurryFront;
+
+impl CurryOrder for CurryBack
+{
+
+}
+impl CurryOrder for CurryFront
+{
+
+}
+
+/// Trait for composing two functions, where the arguments of the function being composed with are moved to the start of the argument-list
+/// 
+/// Currying composition:
+/// h(x, ...) = g ∘ f = g(f(x), ...)
+/// 
+/// Otherwise works just like [compose](crate::Compose::compose), and is identical for non-currying composition.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f :: u8 -> f32 -> f32
+/// let g = |x: f32, y: f32| x - y;
+/// let f = |x: u8| x as f32;
+/// 
+/// let gf = g.compose_front(f);
+/// 
+/// let x = 1;
+/// let y = 2.0;
+/// 
+/// // note here the argument x stays in front of the args in gf
+/// assert_eq!(gf(x, y), g(f(x), y));
+/// 
+/// // the same composition, with x shifted to the end of the args
+/// let gf = g.compose(f);
+/// 
+/// assert_eq!(gf(y, x), g(f(x), y));
+/// ```
+#[const_trait]
+pub trait ComposeFront<F, XG, XF>: Sized
+{
+    /// Composing two functions, with the arguments of f first
+    /// 
+    /// h(x, ...) = g ∘ f = g(f(x), ...)
+    fn compose_front(self, with: F) -> Composition<Self, F, XG, XF, CurryFront>;
+}
+
+impl<G, F, XG, XF> const ComposeFront<F, XG, XF> for G
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    Self: FnOnce<XG>,
+    F: FnOnce<XF, Output = Head<XG>>,
+    (XF, Tail<XG>): TupleConcat<XF, Tail<XG>>,
+    ConcatTuples<XF, Tail<XG>>: Tuple,
+    Composition<Self, F, XG, XF, CurryFront>: FnOnce<ConcatTuples<XF, Tail<XG>>>
+{
+    fn compose_front(self, with: F) -> Composition<Self, F, XG, XF, CurryFront>
+    {
+        Composition {
+            g: self,
+            f: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<G, F, XG, XF> FnOnce<ConcatTuples<XF, Tail<XG>>> for Composition<G, F, XG, XF, CurryFront>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: FnOnce<XG>,
+    F: FnOnce<XF, Output = Head<XG>>,
+    (XF, Tail<XG>): TupleConcat<XF, Tail<XG>>,
+    ConcatTuples<XF, Tail<XG>>: Tuple + SplitInto<XF, Tail<XG>>,
+    [(); <XF as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<XG>): TupleConcat<(F::Output,), Tail<XG>, Type = XG>
+{
+    type Output = <G as FnOnce<XG>>::Output;
+
+    extern "rust-call" fn call_once(self, args: ConcatTuples<XF, Tail<XG>>) -> Self::Output
+    {
+        let (left, right): (XF, Tail<XG>) = args.split_tuple();
+        self.g.call_once(concat_tuples((self.f.call_once(left),), right))
+    }
+}
+
+impl<G, F, XG, XF> FnMut<ConcatTuples<XF, Tail<XG>>> for Composition<G, F, XG, XF, CurryFront>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: FnMut<XG>,
+    F: FnMut<XF, Output = Head<XG>>,
+    (XF, Tail<XG>): TupleConcat<XF, Tail<XG>>,
+    ConcatTuples<XF, Tail<XG>>: Tuple + SplitInto<XF, Tail<XG>>,
+    [(); <XF as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<XG>): TupleConcat<(F::Output,), Tail<XG>, Type = XG>
+{
+    extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<XF, Tail<XG>>) -> Self::Output
+    {
+        let (left, right) = args.split_tuple();
+        self.g.call_mut(concat_tuples((self.f.call_mut(left),), right))
+    }
+}
+
+impl<G, F, XG, XF> Fn<ConcatTuples<XF, Tail<XG>>> for Composition<G, F, XG, XF, CurryFront>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: Fn<XG>,
+    F: Fn<XF, Output = Head<XG>>,
+    (XF, Tail<XG>): TupleConcat<XF, Tail<XG>>,
+    ConcatTuples<XF, Tail<XG>>: Tuple + SplitInto<XF, Tail<XG>>,
+    [(); <XF as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<XG>): TupleConcat<(F::Output,), Tail<XG>, Type = XG>
+{
+    extern "rust-call" fn call(&self, args: ConcatTuples<XF, Tail<XG>>) -> Self::Output
+    {
+        let (left, right) = args.split_tuple();
+        self.g.call(concat_tuples((self.f.call(left),), right))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,150 @@
+use std::marker::{Tuple, PhantomData};
+use std::ops::{Mul, Shr};
+
+use tupleops::{ConcatTuples, TupleConcat, TupleUnprepend, Tail};
+
+use crate::{Compose, Composition, Then};
+
+/// A thin wrapper around a function, allowing functions to be composed with operators.
+/// 
+/// g * f = g ∘ f = g.compose(f)
+/// 
+/// f >> g = f ; g = f.then(g)
+/// 
+/// X is the argument-list of the function. It has to be a parameter of the wrapper, as otherwise the argument-lists of the operands of * and >> would not appear in the operator impls, which Rust rejects as unconstrained (E0207).
+/// This is also why the wrapper is made with [Func::new](Func::new) rather than as a tuple struct, whose X would have to be given as a PhantomData field.
+/// 
+/// The wrapper itself implements FnOnce, FnMut and Fn by forwarding to the function, so it can be used anywhere the function can.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f :: u8 -> f32
+/// let g = Func::new(|x: f32| x*x);
+/// let f = Func::new(|x: u8| x as f32);
+/// 
+/// let gf = g * f;
+/// 
+/// let x = 2;
+/// 
+/// assert_eq!(gf(x), g(f(x)));
+/// 
+/// // the same composition, in the direction the data flows
+/// let fg = f >> g;
+/// 
+/// assert_eq!(fg(x), gf(x));
+/// 
+/// // g ∘ f ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f ∘ f :: u8 -> u8 -> f32
+/// let g = Func::new(|x: f32, y: f32| x + y);
+/// 
+/// let gff = g * f * f;
+/// 
+/// let x = 1;
+/// let y = 2;
+/// 
+/// assert_eq!(gff(x, y), g(f(x), f(y)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Func<F, X>
+{
+    f: F,
+    phantom: PhantomData<X>
+}
+
+impl<F, X> Func<F, X>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    /// Wraps a function.
+    pub const fn new(f: F) -> Self
+    {
+        Self {
+            f,
+            phantom: PhantomData
+        }
+    }
+
+    /// Unwraps the function.
+    pub fn into_inner(self) -> F
+    {
+        self.f
+    }
+}
+
+impl<G, F, XG, XF> Mul<Func<F, XF>> for Func<G, XG>
+where
+    G: Compose<F, XG, XF>,
+    XG: TupleUnprepend<XG>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple,
+    Composition<G, F, XG, XF>: FnOnce<ConcatTuples<Tail<XG>, XF>>
+{
+    type Output = Func<Composition<G, F, XG, XF>, ConcatTuples<Tail<XG>, XF>>;
+
+    fn mul(self, rhs: Func<F, XF>) -> Self::Output
+    {
+        Func::new(self.f.compose(rhs.f))
+    }
+}
+
+impl<F, G, XF, XG> Shr<Func<G, XG>> for Func<F, XF>
+where
+    F: Then<G, XF, XG>,
+    XG: TupleUnprepend<XG>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple,
+    Composition<G, F, XG, XF>: FnOnce<ConcatTuples<Tail<XG>, XF>>
+{
+    type Output = Func<Composition<G, F, XG, XF>, ConcatTuples<Tail<XG>, XF>>;
+
+    fn shr(self, rhs: Func<G, XG>) -> Self::Output
+    {
+        Func::new(self.f.then(rhs.f))
+    }
+}
+
+impl<F, X> FnOnce<X> for Func<F, X>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        self.f.call_once(args)
+    }
+}
+
+impl<F, X> FnMut<X> for Func<F, X>
+where
+    X: Tuple,
+    F: FnMut<X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        self.f.call_mut(args)
+    }
+}
+
+impl<F, X> Fn<X> for Func<F, X>
+where
+    X: Tuple,
+    F: Fn<X>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        self.f.call(args)
+    }
+}
\ No newline at end of file
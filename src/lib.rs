@@ -3,6 +3,8 @@
 #![feature(fn_traits)]
 #![feature(generic_const_exprs)]
 #![feature(const_trait_impl)]
+#![feature(try_trait_v2)]
+#![feature(async_fn_traits)]
 
 //! https://en.wikipedia.org/wiki/Function_composition
 //! 
@@ -74,6 +76,63 @@ use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength, TupleUnpre
 
 use tuple_split::{TupleSplit, SplitInto};
 
+mod arrow;
+mod async_compose;
+mod auto_curry;
+mod bind;
+mod call_slice;
+mod choice;
+mod compose_all;
+mod compose_at;
+mod compose_join;
+mod compose_splat;
+mod curry;
+mod curry_order;
+mod func;
+mod macros;
+#[cfg(feature = "rayon")]
+mod par;
+mod pipe;
+mod recover;
+mod rewire;
+mod saga;
+#[cfg(feature = "stream")]
+mod stream;
+mod threaded;
+mod timeout;
+mod try_compose;
+mod unwind;
+mod validate;
+mod with_defaults;
+
+pub use arrow::*;
+pub use async_compose::*;
+pub use auto_curry::*;
+pub use bind::*;
+pub use call_slice::*;
+pub use choice::*;
+pub use compose_all::*;
+pub use compose_at::*;
+pub use compose_join::*;
+pub use compose_splat::*;
+pub use curry::*;
+pub use curry_order::*;
+pub use func::*;
+#[cfg(feature = "rayon")]
+pub use par::*;
+pub use pipe::*;
+pub use recover::*;
+pub use rewire::*;
+pub use saga::*;
+#[cfg(feature = "stream")]
+pub use stream::*;
+pub use threaded::*;
+pub use timeout::*;
+pub use try_compose::*;
+pub use unwind::*;
+pub use validate::*;
+pub use with_defaults::*;
+
 /// https://en.wikipedia.org/wiki/Function_composition
 /// 
 /// Trait for composing two functions (currying and non-curring composition)
@@ -170,6 +229,9 @@ where
 /// 
 /// When calling the composition as a function, the leftover arguments of the composition function come first (if curried), then the arguments of the function being composed with.
 /// 
+/// This order is given by the [CurryOrder](CurryOrder) O, which is [CurryBack](CurryBack) by default.
+/// Compositions made with [compose_front](ComposeFront::compose_front) are [CurryFront](CurryFront), where the arguments of the function being composed with come first instead.
+/// 
 /// ```rust
 /// #![feature(generic_const_exprs)]
 /// 
@@ -218,11 +280,11 @@ where
 /// assert_eq!(gff(x, y), g(f(x), f(y)));
 /// ```
 #[derive(Clone, Copy, Debug)]
-pub struct Composition<G, F, XG, XF>
+pub struct Composition<G, F, XG, XF, O = CurryBack>
 {
     g: G,
     f: F,
-    phantom: PhantomData<(XG, XF)>,
+    phantom: PhantomData<(XG, XF, O)>,
 }
 
 impl<G, F, XG, XF> FnOnce<ConcatTuples<Tail<XG>, XF>> for Composition<G, F, XG, XF>
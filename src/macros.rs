@@ -0,0 +1,164 @@
+/// Composes any number of functions, from right to left.
+/// 
+/// compose!(h, g, f) = h ∘ g ∘ f = h.compose(g.compose(f))
+/// 
+/// Each function is composed into the first argument of the function before it, using [compose](crate::Compose::compose), so curried leftover arguments work the same way.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // h ∘ g ∘ f
+/// // where
+/// // h :: f32 -> f32
+/// // g :: u8 -> f32
+/// // f :: bool -> u8
+/// // h ∘ g ∘ f :: bool -> f32
+/// let h = |x: f32| x*x;
+/// let g = |x: u8| x as f32;
+/// let f = |x: bool| x as u8 + 1;
+/// 
+/// let hgf = compose!(h, g, f);
+/// 
+/// let x = true;
+/// 
+/// assert_eq!(hgf(x), h(g(f(x))));
+/// ```
+/// 
+/// If the output of a function does not match the first argument of the function before it, the composition fails to compile.
+/// 
+/// ```rust,compile_fail,E0271
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let h = |x: f32| x*x;
+/// let g = |x: u8| x as f64;
+/// let f = |x: bool| x as u8;
+/// 
+/// // g returns f64, but h takes f32
+/// let hgf = compose!(h, g, f);
+/// ```
+#[macro_export]
+macro_rules! compose {
+    ($f:expr $(,)?) => {
+        $f
+    };
+    ($g:expr, $($f:expr),+ $(,)?) => {
+        $crate::Compose::compose($g, $crate::compose!($($f),+))
+    };
+}
+
+/// Composes any number of functions, from left to right in the direction the data flows.
+/// 
+/// pipe!(f => g => h) = h ∘ g ∘ f = compose!(h, g, f)
+/// 
+/// This is the same as [compose!](crate::compose!) with the functions given in reverse order, just like [then](crate::Then::then) is to [compose](crate::Compose::compose).
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // f ; g ; h
+/// // where
+/// // f :: bool -> u8
+/// // g :: u8 -> f32
+/// // h :: f32 -> f32
+/// // f ; g ; h :: bool -> f32
+/// let f = |x: bool| x as u8 + 1;
+/// let g = |x: u8| x as f32;
+/// let h = |x: f32| x*x;
+/// 
+/// let fgh = pipe!(f => g => h);
+/// 
+/// let x = true;
+/// 
+/// assert_eq!(fgh(x), h(g(f(x))));
+/// assert_eq!(fgh(x), compose!(h, g, f)(x));
+/// ```
+/// 
+/// If the output of a function does not match the first argument of the function after it, the composition fails to compile.
+/// 
+/// ```rust,compile_fail,E0271
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: bool| x as u8;
+/// let g = |x: u8| x as f64;
+/// let h = |x: f32| x*x;
+/// 
+/// // g returns f64, but h takes f32
+/// let fgh = pipe!(f => g => h);
+/// ```
+#[macro_export]
+macro_rules! pipe {
+    (@rev [$($g:expr),*]) => {
+        $crate::compose!($($g),*)
+    };
+    (@rev [$($g:expr),*] $f:expr $(=> $h:expr)*) => {
+        $crate::pipe!(@rev [$f $(, $g)*] $($h)=>*)
+    };
+    ($f:expr $(=> $g:expr)*) => {
+        $crate::pipe!(@rev [$f] $($g)=>*)
+    };
+}
+
+/// Fixes any number of arguments of a function, with `_` marking the arguments left open.
+/// 
+/// partial!(f, _, y, _)(x, z) = f(x, y, z)
+/// 
+/// Each fixed argument is bound using [bind_at](crate::BindAt::bind_at), so if f implements FnMut or Fn, and the fixed arguments implement Clone, the resulting function will also implement these traits.
+/// 
+/// Arguments past the last one listed are left open.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8, z: u8| x*100 + y*10 + z;
+/// 
+/// let g = partial!(f, _, 2, _);
+/// 
+/// assert_eq!(g(1, 3), f(1, 2, 3));
+/// 
+/// let h = partial!(f, 1, _, 3);
+/// 
+/// assert_eq!(h(2), f(1, 2, 3));
+/// 
+/// // pinning the configuration arguments of a composition
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f :: f32 -> f32 -> u8 -> f32
+/// let g = |x: f32, scale: f32, offset: f32| x*scale + offset;
+/// let f = |x: u8| x as f32;
+/// 
+/// let gf = partial!(g.compose(f), 2.0, 1.0);
+/// 
+/// fn call_twice(f: impl Fn(u8) -> f32) -> f32
+/// {
+///     f(1) + f(2)
+/// }
+/// 
+/// assert_eq!(call_twice(gf), 3.0 + 5.0);
+/// ```
+#[macro_export]
+macro_rules! partial {
+    (@bind $f:expr; [$($n:tt)*];) => {
+        $f
+    };
+    (@bind $f:expr; [$($n:tt)*]; _ $(, $($rest:tt)*)?) => {
+        $crate::partial!(@bind $f; [$($n)* + 1]; $($($rest)*)?)
+    };
+    (@bind $f:expr; [$($n:tt)*]; $v:expr $(, $($rest:tt)*)?) => {
+        $crate::partial!(@bind $crate::BindAt::bind_at::<{0 $($n)*}>($f, $v); [$($n)*]; $($($rest)*)?)
+    };
+    ($f:expr $(, $($args:tt)*)?) => {
+        $crate::partial!(@bind $f; []; $($($args)*)?)
+    };
+}
\ No newline at end of file
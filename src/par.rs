@@ -0,0 +1,229 @@
+use std::marker::{Tuple, PhantomData};
+
+use rayon::prelude::*;
+
+/// Trait for calling a function, such as a composition, on each argument-list of a slice in parallel, using [rayon](rayon).
+/// 
+/// The function must implement Fn and Sync, so that it can be shared between threads. A composition is Sync if both of its operands are.
+/// 
+/// The argument-lists must implement Clone, as the function is given a copy of each of them.
+/// 
+/// This requires the `rayon` feature.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: u64 -> u64 -> u64
+/// // f :: u64 -> u64
+/// // g ∘ f :: u64 -> u64 -> u64
+/// let g = |x: u64, m: u64| x % m;
+/// let f = |n: u64| (1..=n).product::<u64>();
+/// 
+/// let gf = g.compose(f);
+/// 
+/// let inputs: Vec<(u64, u64)> = (1..=10).map(|n| (1000, n)).collect();
+/// 
+/// // the outputs are in the same order as the inputs
+/// assert_eq!(gf.par_map_slice(&inputs), inputs.iter().map(|&(m, n)| gf(m, n)).collect::<Vec<_>>());
+/// ```
+pub trait ParMapSlice<X>: Sized
+where
+    X: Tuple,
+    Self: Fn<X>
+{
+    /// Calling a function on each argument-list of a slice in parallel
+    fn par_map_slice(&self, inputs: &[X]) -> Vec<<Self as FnOnce<X>>::Output>;
+}
+
+impl<F, X> ParMapSlice<X> for F
+where
+    X: Tuple + Clone + Sync,
+    Self: Fn<X> + Sync,
+    <Self as FnOnce<X>>::Output: Send
+{
+    fn par_map_slice(&self, inputs: &[X]) -> Vec<<Self as FnOnce<X>>::Output>
+    {
+        inputs.par_iter()
+            .map(|args| self.call(args.clone()))
+            .collect()
+    }
+}
+
+/// Trait for combining two functions taking the same arguments into one, returning a pair of both their outputs, where both functions are called in parallel using [rayon](rayon).
+/// 
+/// h(x) = f &&& g = (f(x), g(x))
+/// 
+/// This is the same as [fanout](crate::ArrowFanout::fanout), except f and g may run on different threads, so their arguments and outputs must implement Send.
+/// The resulting function implements FnOnce or FnMut if both operands do and are Send, and Fn if both operands do and are Sync.
+/// 
+/// More than two branches may be evaluated in parallel by nesting.
+/// 
+/// This requires the `rayon` feature.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // h ∘ ((f₁ &&& f₂) &&& f₃)
+/// // where
+/// // h :: (u64, u64) -> u64 -> u64
+/// // f₁ :: u64 -> u64
+/// // f₂ :: u64 -> u64
+/// // f₃ :: u64 -> u64
+/// // h ∘ ((f₁ &&& f₂) &&& f₃) :: u64 -> u64
+/// let h = |(x, y): (u64, u64), z: u64| x + y + z;
+/// let f1 = |n: u64| (1..=n).sum::<u64>();
+/// let f2 = |n: u64| (1..=n).product::<u64>();
+/// let f3 = |n: u64| n*n;
+/// 
+/// let f = f1.par_fanout(f2).par_fanout(f3);
+/// 
+/// let x = 5;
+/// 
+/// assert_eq!(f(x), ((f1(x), f2(x)), f3(x)));
+/// 
+/// let hf = h.compose_splat(f);
+/// 
+/// assert_eq!(hf(x), 15 + 120 + 25);
+/// ```
+#[const_trait]
+pub trait ParFanout<G, X>: Sized
+{
+    /// Combining two functions taking the same arguments, calling them in parallel
+    /// 
+    /// h(x) = f &&& g = (f(x), g(x))
+    fn par_fanout(self, with: G) -> ParallelFanout<Self, G, X>;
+}
+
+impl<F, G, X> const ParFanout<G, X> for F
+where
+    X: Tuple + Clone + Send,
+    Self: FnOnce<X>,
+    G: FnOnce<X>
+{
+    fn par_fanout(self, with: G) -> ParallelFanout<Self, G, X>
+    {
+        ParallelFanout {
+            f: self,
+            g: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing two functions taking the same arguments, called in parallel as one returning a pair of their outputs.
+/// 
+/// See [par_fanout](ParFanout::par_fanout).
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8| x + y;
+/// let g = |x: u8, y: u8| x*y;
+/// 
+/// let fg = f.par_fanout(g);
+/// 
+/// assert_eq!(fg(2, 3), (5, 6));
+/// ```
+/// 
+/// The functions must be Sync for the result to implement Fn. Functions which are only Send still result in FnOnce and FnMut.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::Cell;
+/// 
+/// use currycompose::*;
+/// 
+/// fn require_fn_mut(_: impl FnMut(u8) -> (u8, u8)) {}
+/// 
+/// let count = Cell::new(0);
+/// 
+/// let f = |x: u8| x + 1;
+/// let g = move |x: u8| {count.set(count.get() + 1); x};
+/// 
+/// // g is Send, but not Sync
+/// require_fn_mut(f.par_fanout(g));
+/// ```
+/// 
+/// ```rust,compile_fail
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::Cell;
+/// 
+/// use currycompose::*;
+/// 
+/// fn require_fn(_: impl Fn(u8) -> (u8, u8)) {}
+/// 
+/// let count = Cell::new(0);
+/// 
+/// let f = |x: u8| x + 1;
+/// let g = move |x: u8| {count.set(count.get() + 1); x};
+/// 
+/// // g is Send, but not Sync
+/// require_fn(f.par_fanout(g));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ParallelFanout<F, G, X>
+{
+    f: F,
+    g: G,
+    phantom: PhantomData<X>,
+}
+
+impl<F, G, X> FnOnce<X> for ParallelFanout<F, G, X>
+where
+    X: Tuple + Clone + Send,
+    F: FnOnce<X> + Send,
+    G: FnOnce<X> + Send,
+    F::Output: Send,
+    G::Output: Send
+{
+    type Output = (F::Output, G::Output);
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        let Self {f, g, ..} = self;
+        let args_f = args.clone();
+        rayon::join(move || f.call_once(args_f), move || g.call_once(args))
+    }
+}
+
+impl<F, G, X> FnMut<X> for ParallelFanout<F, G, X>
+where
+    X: Tuple + Clone + Send,
+    F: FnMut<X> + Send,
+    G: FnMut<X> + Send,
+    <F as FnOnce<X>>::Output: Send,
+    <G as FnOnce<X>>::Output: Send
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        let Self {f, g, ..} = self;
+        let args_f = args.clone();
+        rayon::join(move || f.call_mut(args_f), move || g.call_mut(args))
+    }
+}
+
+impl<F, G, X> Fn<X> for ParallelFanout<F, G, X>
+where
+    X: Tuple + Clone + Send,
+    F: Fn<X> + Send + Sync,
+    G: Fn<X> + Send + Sync,
+    <F as FnOnce<X>>::Output: Send,
+    <G as FnOnce<X>>::Output: Send
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        let Self {f, g, ..} = self;
+        let args_f = args.clone();
+        rayon::join(move || f.call(args_f), move || g.call(args))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,110 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, TupleUnprepend, Head, Tail};
+
+use crate::Composition;
+
+/// Trait for composing two functions in reverse order, reading left-to-right in the direction the data flows.
+/// 
+/// h(x) = f ; g = g ∘ f = g(f(x))
+/// 
+/// f.then(g) is the exact same as g.compose(f), and yields the same [Composition](crate::Composition), with the same curried leftover arguments.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // f ; g
+/// // where
+/// // f :: u8 -> f32
+/// // g :: f32 -> f32 -> f32
+/// // f ; g :: f32 -> u8 -> f32
+/// let f = |x: u8| x as f32;
+/// let g = |x: f32, y: f32| x - y;
+/// 
+/// let fg = f.then(g);
+/// 
+/// let x = 1;
+/// let y = 2.0;
+/// 
+/// assert_eq!(fg(y, x), g(f(x), y));
+/// assert_eq!(fg(y, x), g.compose(f)(y, x));
+/// ```
+#[const_trait]
+pub trait Then<G, XF, XG>: Sized
+{
+    /// Composing two functions in reverse order
+    /// 
+    /// h(x) = f ; g = g(f(x))
+    fn then(self, g: G) -> Composition<G, Self, XG, XF>;
+
+    /// Composing two functions in reverse order. Same as [then](Then::then).
+    /// 
+    /// h(x) = f ; g = g(f(x))
+    fn and_then(self, g: G) -> Composition<G, Self, XG, XF>;
+}
+
+impl<F, G, XF, XG> const Then<G, XF, XG> for F
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: FnOnce<XG>,
+    Self: FnOnce<XF, Output = Head<XG>>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple,
+    Composition<G, Self, XG, XF>: FnOnce<ConcatTuples<Tail<XG>, XF>>
+{
+    fn then(self, g: G) -> Composition<G, Self, XG, XF>
+    {
+        Composition {
+            g,
+            f: self,
+            phantom: PhantomData
+        }
+    }
+
+    fn and_then(self, g: G) -> Composition<G, Self, XG, XF>
+    {
+        Composition {
+            g,
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// Trait for passing any value into a function, reading left-to-right in the direction the data flows.
+/// 
+/// x.pipe(f) = f(x)
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| x as f32;
+/// let g = |x: f32| x*x;
+/// 
+/// let x = 2;
+/// 
+/// assert_eq!(x.pipe(f).pipe(g), g(f(x)));
+/// assert_eq!(x.pipe(f.then(g)), g(f(x)));
+/// ```
+pub trait Pipe: Sized
+{
+    /// Passes the value into a function
+    /// 
+    /// x.pipe(f) = f(x)
+    fn pipe<F>(self, f: F) -> F::Output
+    where
+        F: FnOnce<(Self,)>
+    {
+        f.call_once((self,))
+    }
+}
+
+impl<T> Pipe for T
+{
+
+}
\ No newline at end of file
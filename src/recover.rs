@@ -0,0 +1,343 @@
+use std::marker::{Tuple, PhantomData};
+use std::ops::{Try, FromResidual, ControlFlow};
+
+/// Trait for deciding whether a failed function should be called again.
+/// 
+/// R is the residual of the output of the function, such as Result<Infallible, E> for Result<T, E>.
+/// 
+/// This is implemented for usize, as the maximum number of retries, and for closures taking the number of failed attempts so far.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::Cell;
+/// use std::convert::Infallible;
+/// 
+/// use currycompose::*;
+/// 
+/// #[derive(Debug, PartialEq)]
+/// enum Error
+/// {
+///     Busy,
+///     NotFound
+/// }
+/// 
+/// // only retries errors which may go away
+/// struct WhenBusy;
+/// 
+/// impl RetryPolicy<Result<Infallible, Error>> for WhenBusy
+/// {
+///     fn retry(&self, _attempt: usize, residual: &Result<Infallible, Error>) -> bool
+///     {
+///         matches!(residual, Err(Error::Busy))
+///     }
+/// }
+/// 
+/// let calls = Cell::new(0);
+/// let f = (|x: u8| {
+///     calls.set(calls.get() + 1);
+///     match calls.get()
+///     {
+///         1 => Err(Error::Busy),
+///         2 => Err(Error::NotFound),
+///         _ => Ok(x)
+///     }
+/// }).retry_with(WhenBusy);
+/// 
+/// assert_eq!(f(1), Err(Error::NotFound));
+/// assert_eq!(f(1), Ok(1));
+/// ```
+pub trait RetryPolicy<R>
+{
+    /// Whether to call the function again, after the given number of failed attempts.
+    fn retry(&self, attempt: usize, residual: &R) -> bool;
+}
+
+impl<R> RetryPolicy<R> for usize
+{
+    fn retry(&self, attempt: usize, _residual: &R) -> bool
+    {
+        attempt <= *self
+    }
+}
+
+impl<P, R> RetryPolicy<R> for P
+where
+    P: Fn(usize) -> bool
+{
+    fn retry(&self, attempt: usize, _residual: &R) -> bool
+    {
+        self(attempt)
+    }
+}
+
+/// Trait for calling a fallible function again when it fails.
+/// 
+/// f may return any type implementing [Try](std::ops::Try), such as Result or Option. The arguments are cloned for each attempt, so they must implement Clone.
+/// 
+/// Since the function may be called several times, it must implement FnMut. If it also implements Fn, the resulting function will also implement Fn.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::Cell;
+/// 
+/// use currycompose::*;
+/// 
+/// let calls = Cell::new(0);
+/// let f = |x: u8| {
+///     calls.set(calls.get() + 1);
+///     if calls.get() % 3 == 0 {Ok(x)} else {Err(calls.get())}
+/// };
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: Result<u8, usize> -> bool
+/// // f :: u8 -> Result<u8, usize>
+/// // g ∘ f :: u8 -> bool
+/// let g = |x: Result<u8, usize>| x.is_ok();
+/// 
+/// // succeeds on the third attempt
+/// let gf = g.compose(f.retry(2));
+/// 
+/// assert!(gf(1));
+/// 
+/// // gives up after the second attempt
+/// let gf = g.compose(f.retry(1));
+/// 
+/// assert!(!gf(1));
+/// 
+/// // closures may also be used as policies
+/// let f = f.retry_with(|attempt| attempt < 3);
+/// 
+/// assert_eq!(f(1), Ok(1));
+/// ```
+#[const_trait]
+pub trait Retry<X>: Sized
+{
+    /// Calling a function again up to n times, while it fails
+    fn retry(self, n: usize) -> Retrying<Self, X, usize>;
+
+    /// Calling a function again while it fails, as long as the policy allows it
+    fn retry_with<P>(self, policy: P) -> Retrying<Self, X, P>;
+}
+
+impl<F, X> const Retry<X> for F
+where
+    X: Tuple + Clone,
+    Self: FnMut<X>,
+    <Self as FnOnce<X>>::Output: Try
+{
+    fn retry(self, n: usize) -> Retrying<Self, X, usize>
+    {
+        self.retry_with(n)
+    }
+
+    fn retry_with<P>(self, policy: P) -> Retrying<Self, X, P>
+    {
+        Retrying {
+            f: self,
+            policy,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a fallible function, which is called again when it fails, as long as the policy P allows it.
+/// 
+/// See [retry](Retry::retry) and [retry_with](Retry::retry_with).
+#[derive(Clone, Copy, Debug)]
+pub struct Retrying<F, X, P>
+{
+    f: F,
+    policy: P,
+    phantom: PhantomData<X>
+}
+
+fn call_retrying<F, X, P>(mut f: F, policy: &P, args: X) -> F::Output
+where
+    X: Tuple + Clone,
+    F: FnMut<X>,
+    F::Output: Try,
+    P: RetryPolicy<<F::Output as Try>::Residual>
+{
+    let mut attempt = 0;
+    loop
+    {
+        match f.call_mut(args.clone()).branch()
+        {
+            ControlFlow::Continue(y) => return F::Output::from_output(y),
+            ControlFlow::Break(residual) => {
+                attempt += 1;
+                if !policy.retry(attempt, &residual)
+                {
+                    return F::Output::from_residual(residual)
+                }
+            }
+        }
+    }
+}
+
+impl<F, X, P> FnOnce<X> for Retrying<F, X, P>
+where
+    X: Tuple + Clone,
+    F: FnMut<X>,
+    F::Output: Try,
+    P: RetryPolicy<<F::Output as Try>::Residual>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(mut self, args: X) -> Self::Output
+    {
+        call_retrying(&mut self.f, &self.policy, args)
+    }
+}
+
+impl<F, X, P> FnMut<X> for Retrying<F, X, P>
+where
+    X: Tuple + Clone,
+    F: FnMut<X>,
+    F::Output: Try,
+    P: RetryPolicy<<F::Output as Try>::Residual>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        call_retrying(&mut self.f, &self.policy, args)
+    }
+}
+
+impl<F, X, P> Fn<X> for Retrying<F, X, P>
+where
+    X: Tuple + Clone,
+    F: Fn<X>,
+    F::Output: Try,
+    P: RetryPolicy<<F::Output as Try>::Residual>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        call_retrying(&self.f, &self.policy, args)
+    }
+}
+
+/// Trait for falling back to an alternative function when a fallible function fails.
+/// 
+/// or_else(f, g)(x) = f(x) or else g(x)
+/// 
+/// f may return any type implementing [Try](std::ops::Try), such as Result or Option, and g must return the same type. Both are given the same arguments, which are cloned for f, so they must implement Clone.
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::collections::HashMap;
+/// 
+/// use currycompose::*;
+/// 
+/// let cache = HashMap::from([(1, "one")]);
+/// 
+/// let cached = |x: u8| cache.get(&x).copied().ok_or(x);
+/// let computed = |x: u8| if x < 10 {Ok("small")} else {Err(x)};
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: Result<&str, u8> -> &str
+/// // f :: u8 -> Result<&str, u8>
+/// // g ∘ f :: u8 -> &str
+/// let g = |x: Result<&'static str, u8>| x.unwrap_or("large");
+/// 
+/// let gf = g.compose(cached.or_else(computed));
+/// 
+/// assert_eq!(gf(1), "one");
+/// assert_eq!(gf(2), "small");
+/// assert_eq!(gf(20), "large");
+/// ```
+#[const_trait]
+pub trait OrElse<G, X>: Sized
+{
+    /// Falling back to an alternative function when a function fails
+    /// 
+    /// or_else(f, g)(x) = f(x) or else g(x)
+    fn or_else(self, alternative: G) -> Fallback<Self, G, X>;
+}
+
+impl<F, G, X> const OrElse<G, X> for F
+where
+    X: Tuple + Clone,
+    Self: FnOnce<X>,
+    <Self as FnOnce<X>>::Output: Try,
+    G: FnOnce<X, Output = <Self as FnOnce<X>>::Output>
+{
+    fn or_else(self, alternative: G) -> Fallback<Self, G, X>
+    {
+        Fallback {
+            f: self,
+            g: alternative,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a fallible function, with an alternative function to call when it fails.
+/// 
+/// See [or_else](OrElse::or_else).
+#[derive(Clone, Copy, Debug)]
+pub struct Fallback<F, G, X>
+{
+    f: F,
+    g: G,
+    phantom: PhantomData<X>
+}
+
+impl<F, G, X> FnOnce<X> for Fallback<F, G, X>
+where
+    X: Tuple + Clone,
+    F: FnOnce<X>,
+    F::Output: Try,
+    G: FnOnce<X, Output = F::Output>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        match self.f.call_once(args.clone()).branch()
+        {
+            ControlFlow::Continue(y) => F::Output::from_output(y),
+            ControlFlow::Break(_) => self.g.call_once(args)
+        }
+    }
+}
+
+impl<F, G, X> FnMut<X> for Fallback<F, G, X>
+where
+    X: Tuple + Clone,
+    F: FnMut<X>,
+    F::Output: Try,
+    G: FnMut<X, Output = F::Output>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        match self.f.call_mut(args.clone()).branch()
+        {
+            ControlFlow::Continue(y) => F::Output::from_output(y),
+            ControlFlow::Break(_) => self.g.call_mut(args)
+        }
+    }
+}
+
+impl<F, G, X> Fn<X> for Fallback<F, G, X>
+where
+    X: Tuple + Clone,
+    F: Fn<X>,
+    F::Output: Try,
+    G: Fn<X, Output = F::Output>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        match self.f.call(args.clone()).branch()
+        {
+            ControlFlow::Continue(y) => F::Output::from_output(y),
+            ControlFlow::Break(_) => self.g.call(args)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,624 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength, TupleUnprepend, Head, Tail, TupleOption, OptionTuple, option_tuple, TupleAllSome, all_some};
+
+use tuple_split::{TupleSplit, SplitInto, Left, Right};
+
+use crate::{SkipAt, ArgAt};
+
+/// The arguments of a function with its first two arguments swapped.
+pub type FlipArgs<X> = ConcatTuples<(Head<Tail<X>>, Head<X>), Tail<Tail<X>>>;
+
+/// Trait for swapping the first two arguments of a function.
+/// 
+/// flip(f)(y, x, ...) = f(x, y, ...)
+/// 
+/// If f implements FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f :: f32 -> u8 -> f32
+/// let g = |x: f32, y: f32| x - y;
+/// let f = |x: u8| x as f32;
+/// 
+/// // the argument of f first again
+/// // flip(g ∘ f) :: u8 -> f32 -> f32
+/// let gf = g.compose(f).flip();
+/// 
+/// let x = 1;
+/// let y = 2.0;
+/// 
+/// assert_eq!(gf(x, y), g(f(x), y));
+/// ```
+#[const_trait]
+pub trait Flip<X>: Sized
+{
+    /// Swapping the first two arguments of a function
+    /// 
+    /// flip(f)(y, x, ...) = f(x, y, ...)
+    fn flip(self) -> Flipped<Self, X>;
+}
+
+impl<F, X> const Flip<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn flip(self) -> Flipped<Self, X>
+    {
+        Flipped {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function with its first two arguments swapped.
+/// 
+/// See [flip](Flip::flip).
+#[derive(Clone, Copy, Debug)]
+pub struct Flipped<F, X>
+{
+    f: F,
+    phantom: PhantomData<X>
+}
+
+impl<F, X> FnOnce<FlipArgs<X>> for Flipped<F, X>
+where
+    X: Tuple + TupleUnprepend<X>,
+    Tail<X>: TupleUnprepend<Tail<X>>,
+    F: FnOnce<X>,
+    ((Head<Tail<X>>, Head<X>), Tail<Tail<X>>): TupleConcat<(Head<Tail<X>>, Head<X>), Tail<Tail<X>>>,
+    FlipArgs<X>: Tuple + SplitInto<(Head<Tail<X>>, Head<X>), Tail<Tail<X>>>,
+    [(); <(Head<Tail<X>>, Head<X>) as TupleLength>::LENGTH]:,
+    ((Head<X>, Head<Tail<X>>), Tail<Tail<X>>): TupleConcat<(Head<X>, Head<Tail<X>>), Tail<Tail<X>>, Type = X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: FlipArgs<X>) -> Self::Output
+    {
+        let ((y, x), rest) = args.split_tuple();
+        self.f.call_once(concat_tuples((x, y), rest))
+    }
+}
+
+impl<F, X> FnMut<FlipArgs<X>> for Flipped<F, X>
+where
+    X: Tuple + TupleUnprepend<X>,
+    Tail<X>: TupleUnprepend<Tail<X>>,
+    F: FnMut<X>,
+    ((Head<Tail<X>>, Head<X>), Tail<Tail<X>>): TupleConcat<(Head<Tail<X>>, Head<X>), Tail<Tail<X>>>,
+    FlipArgs<X>: Tuple + SplitInto<(Head<Tail<X>>, Head<X>), Tail<Tail<X>>>,
+    [(); <(Head<Tail<X>>, Head<X>) as TupleLength>::LENGTH]:,
+    ((Head<X>, Head<Tail<X>>), Tail<Tail<X>>): TupleConcat<(Head<X>, Head<Tail<X>>), Tail<Tail<X>>, Type = X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: FlipArgs<X>) -> Self::Output
+    {
+        let ((y, x), rest) = args.split_tuple();
+        self.f.call_mut(concat_tuples((x, y), rest))
+    }
+}
+
+impl<F, X> Fn<FlipArgs<X>> for Flipped<F, X>
+where
+    X: Tuple + TupleUnprepend<X>,
+    Tail<X>: TupleUnprepend<Tail<X>>,
+    F: Fn<X>,
+    ((Head<Tail<X>>, Head<X>), Tail<Tail<X>>): TupleConcat<(Head<Tail<X>>, Head<X>), Tail<Tail<X>>>,
+    FlipArgs<X>: Tuple + SplitInto<(Head<Tail<X>>, Head<X>), Tail<Tail<X>>>,
+    [(); <(Head<Tail<X>>, Head<X>) as TupleLength>::LENGTH]:,
+    ((Head<X>, Head<Tail<X>>), Tail<Tail<X>>): TupleConcat<(Head<X>, Head<Tail<X>>), Tail<Tail<X>>, Type = X>
+{
+    extern "rust-call" fn call(&self, args: FlipArgs<X>) -> Self::Output
+    {
+        let ((y, x), rest) = args.split_tuple();
+        self.f.call(concat_tuples((x, y), rest))
+    }
+}
+
+/// Marks argument I of a function, in a [permutation](Permute::permute).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Arg<const I: usize>;
+
+/// Helper trait for accessing the element at index I of a tuple.
+#[doc(hidden)]
+pub trait TupleIndex<const I: usize>: Tuple
+{
+    type Output;
+
+    fn tuple_index(&self) -> &Self::Output;
+    fn tuple_index_mut(&mut self) -> &mut Self::Output;
+}
+
+macro_rules! impl_tuple_index {
+    ([$($t:ident),*] []) => {};
+    ([$($t:ident),*] [($i:tt, $ti:ident) $(, ($j:tt, $tj:ident))*]) => {
+        impl<$($t,)*> TupleIndex<$i> for ($($t,)*)
+        {
+            type Output = $ti;
+
+            fn tuple_index(&self) -> &Self::Output
+            {
+                &self.$i
+            }
+            fn tuple_index_mut(&mut self) -> &mut Self::Output
+            {
+                &mut self.$i
+            }
+        }
+
+        impl_tuple_index!([$($t),*] [$(($j, $tj)),*]);
+    };
+}
+
+macro_rules! impl_tuple_indices {
+    ([$(($i:tt, $t:ident)),*] []) => {};
+    ([$(($i:tt, $t:ident)),*] [($j:tt, $u:ident) $(, ($k:tt, $v:ident))*]) => {
+        impl_tuple_index!([$($t,)* $u] [$(($i, $t),)* ($j, $u)]);
+
+        impl_tuple_indices!([$(($i, $t),)* ($j, $u)] [$(($k, $v)),*]);
+    };
+}
+
+impl_tuple_indices!([] [
+    (0, T0),
+    (1, T1),
+    (2, T2),
+    (3, T3),
+    (4, T4),
+    (5, T5),
+    (6, T6),
+    (7, T7),
+    (8, T8),
+    (9, T9),
+    (10, T10),
+    (11, T11)
+]);
+
+const fn is_permutation(indices: &[usize], length: usize) -> bool
+{
+    if indices.len() != length
+    {
+        return false
+    }
+    let mut i = 0;
+    while i < indices.len()
+    {
+        if indices[i] >= length
+        {
+            return false
+        }
+        let mut j = 0;
+        while j < i
+        {
+            if indices[i] == indices[j]
+            {
+                return false
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Trait for a tuple of [Arg](Arg) markers, describing a permutation of the argument-list X.
+/// 
+/// Argument k of the permuted function is passed into argument I of the original function, where Arg<I> is element k of the permutation.
+/// 
+/// Every argument must be given exactly once, which is checked at compile-time.
+pub trait Permutation<X>
+{
+    /// The argument-list of the permuted function.
+    type Args: Tuple;
+
+    /// Whether every argument is given exactly once.
+    const IS_PERMUTATION: bool;
+
+    /// Rearranges the arguments of the permuted function into the arguments of the original function.
+    fn permute_args(args: Self::Args) -> X;
+}
+
+struct AssertPermutation<P, X>(PhantomData<(P, X)>);
+
+impl<P, X> AssertPermutation<P, X>
+where
+    P: Permutation<X>
+{
+    const OK: () = assert!(P::IS_PERMUTATION, "not a permutation of the argument-list");
+}
+
+macro_rules! impl_permutation {
+    ($(($i:ident, $x:ident)),+) => {
+        impl<X, $(const $i: usize,)+> Permutation<X> for ($(Arg<$i>,)+)
+        where
+            X: Tuple + TupleLength + TupleOption<X> $(+ TupleIndex<$i>)+,
+            OptionTuple<X>: TupleAllSome<OptionTuple<X>, Type = X> $(+ TupleIndex<$i, Output = Option<<X as TupleIndex<$i>>::Output>>)+
+        {
+            type Args = ($(<X as TupleIndex<$i>>::Output,)+);
+
+            const IS_PERMUTATION: bool = is_permutation(&[$($i),+], X::LENGTH);
+
+            fn permute_args(($($x,)+): Self::Args) -> X
+            {
+                let () = AssertPermutation::<Self, X>::OK;
+                let mut slots = option_tuple(None::<X>);
+                $(
+                    *TupleIndex::<$i>::tuple_index_mut(&mut slots) = Some($x);
+                )+
+                match all_some(slots)
+                {
+                    Ok(args) => args,
+                    Err(_) => unreachable!()
+                }
+            }
+        }
+    };
+}
+
+impl_permutation!((I0, x0));
+impl_permutation!((I0, x0), (I1, x1));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4), (I5, x5));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4), (I5, x5), (I6, x6));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4), (I5, x5), (I6, x6), (I7, x7));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4), (I5, x5), (I6, x6), (I7, x7), (I8, x8));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4), (I5, x5), (I6, x6), (I7, x7), (I8, x8), (I9, x9));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4), (I5, x5), (I6, x6), (I7, x7), (I8, x8), (I9, x9), (I10, x10));
+impl_permutation!((I0, x0), (I1, x1), (I2, x2), (I3, x3), (I4, x4), (I5, x5), (I6, x6), (I7, x7), (I8, x8), (I9, x9), (I10, x10), (I11, x11));
+
+/// Trait for rearranging the arguments of a function by a compile-time permutation.
+/// 
+/// permute::<(Arg<2>, Arg<0>, Arg<1>)>(f)(z, x, y) = f(x, y, z)
+/// 
+/// The permutation P is a tuple of [Arg](Arg) markers. Argument k of the permuted function is passed into argument I of f, where Arg<I> is element k of P.
+/// 
+/// If f implements FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> bool -> f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f :: bool -> f32 -> u8 -> f32
+/// let g = |x: f32, neg: bool, y: f32| if neg {-x - y} else {x + y};
+/// let f = |x: u8| x as f32;
+/// 
+/// // permute(g ∘ f) :: u8 -> f32 -> bool -> f32
+/// let gf = g.compose(f).permute::<(Arg<2>, Arg<1>, Arg<0>)>();
+/// 
+/// let x = 1;
+/// let y = 2.0;
+/// let neg = true;
+/// 
+/// assert_eq!(gf(x, y, neg), g(f(x), neg, y));
+/// ```
+/// 
+/// Giving an argument twice is not a permutation, and fails to compile.
+/// 
+/// ```rust,compile_fail,E0080
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8, y: u8| x - y;
+/// 
+/// let g = f.permute::<(Arg<0>, Arg<0>)>();
+/// 
+/// g(1, 2);
+/// ```
+#[const_trait]
+pub trait Permute<X>: Sized
+{
+    /// Rearranging the arguments of a function by the permutation P
+    /// 
+    /// permute::<(Arg<2>, Arg<0>, Arg<1>)>(f)(z, x, y) = f(x, y, z)
+    fn permute<P>(self) -> Permuted<Self, X, P>
+    where
+        P: Permutation<X>;
+}
+
+impl<F, X> const Permute<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn permute<P>(self) -> Permuted<Self, X, P>
+    where
+        P: Permutation<X>
+    {
+        Permuted {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function with its arguments rearranged by the permutation P.
+/// 
+/// See [permute](Permute::permute).
+#[derive(Clone, Copy, Debug)]
+pub struct Permuted<F, X, P>
+{
+    f: F,
+    phantom: PhantomData<(X, P)>
+}
+
+impl<F, X, P> FnOnce<P::Args> for Permuted<F, X, P>
+where
+    X: Tuple,
+    P: Permutation<X>,
+    F: FnOnce<X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: P::Args) -> Self::Output
+    {
+        self.f.call_once(P::permute_args(args))
+    }
+}
+
+impl<F, X, P> FnMut<P::Args> for Permuted<F, X, P>
+where
+    X: Tuple,
+    P: Permutation<X>,
+    F: FnMut<X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: P::Args) -> Self::Output
+    {
+        self.f.call_mut(P::permute_args(args))
+    }
+}
+
+impl<F, X, P> Fn<P::Args> for Permuted<F, X, P>
+where
+    X: Tuple,
+    P: Permutation<X>,
+    F: Fn<X>
+{
+    extern "rust-call" fn call(&self, args: P::Args) -> Self::Output
+    {
+        self.f.call(P::permute_args(args))
+    }
+}
+
+/// Trait for passing one argument into two adjacent arguments of a function.
+/// 
+/// duplicate::<1>(f)(x, y, z) = f(x, y, y, z)
+/// 
+/// Arguments N and N + 1 of f must be of the same type, which must implement Clone. The resulting function takes this argument once, at index N.
+/// 
+/// If f implements FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: f32, y: f32, z: f32| x*y + z;
+/// 
+/// // squaring the first argument
+/// let g = f.duplicate::<0>();
+/// 
+/// assert_eq!(g(3.0, 1.0), f(3.0, 3.0, 1.0));
+/// ```
+#[const_trait]
+pub trait Duplicate<X>: Sized
+{
+    /// Passing one argument into argument N and N + 1 of a function
+    /// 
+    /// duplicate::<1>(f)(x, y, z) = f(x, y, y, z)
+    fn duplicate<const N: usize>(self) -> Duplicated<Self, X, N>;
+}
+
+impl<F, X> const Duplicate<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn duplicate<const N: usize>(self) -> Duplicated<Self, X, N>
+    {
+        Duplicated {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function with one argument passed into both argument N and N + 1.
+/// 
+/// See [duplicate](Duplicate::duplicate).
+#[derive(Clone, Copy, Debug)]
+pub struct Duplicated<F, X, const N: usize>
+{
+    f: F,
+    phantom: PhantomData<X>
+}
+
+impl<F, X, const N: usize> FnOnce<SkipAt<X, N>> for Duplicated<F, X, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Right<X, N>: TupleUnprepend<Right<X, N>>,
+    ArgAt<X, N>: Clone,
+    Tail<Right<X, N>>: TupleIndex<0, Output = ArgAt<X, N>>,
+    F: FnOnce<X>,
+    (Left<X, N>, Tail<Right<X, N>>): TupleConcat<Left<X, N>, Tail<Right<X, N>>>,
+    SkipAt<X, N>: Tuple + SplitInto<Left<X, N>, Tail<Right<X, N>>>,
+    [(); <Left<X, N> as TupleLength>::LENGTH]:,
+    ((ArgAt<X, N>,), Tail<Right<X, N>>): TupleConcat<(ArgAt<X, N>,), Tail<Right<X, N>>, Type = Right<X, N>>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: SkipAt<X, N>) -> Self::Output
+    {
+        let (before, after): (Left<X, N>, Tail<Right<X, N>>) = args.split_tuple();
+        let value = TupleIndex::<0>::tuple_index(&after).clone();
+        self.f.call_once(concat_tuples(before, concat_tuples((value,), after)))
+    }
+}
+
+impl<F, X, const N: usize> FnMut<SkipAt<X, N>> for Duplicated<F, X, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Right<X, N>: TupleUnprepend<Right<X, N>>,
+    ArgAt<X, N>: Clone,
+    Tail<Right<X, N>>: TupleIndex<0, Output = ArgAt<X, N>>,
+    F: FnMut<X>,
+    (Left<X, N>, Tail<Right<X, N>>): TupleConcat<Left<X, N>, Tail<Right<X, N>>>,
+    SkipAt<X, N>: Tuple + SplitInto<Left<X, N>, Tail<Right<X, N>>>,
+    [(); <Left<X, N> as TupleLength>::LENGTH]:,
+    ((ArgAt<X, N>,), Tail<Right<X, N>>): TupleConcat<(ArgAt<X, N>,), Tail<Right<X, N>>, Type = Right<X, N>>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: SkipAt<X, N>) -> Self::Output
+    {
+        let (before, after): (Left<X, N>, Tail<Right<X, N>>) = args.split_tuple();
+        let value = TupleIndex::<0>::tuple_index(&after).clone();
+        self.f.call_mut(concat_tuples(before, concat_tuples((value,), after)))
+    }
+}
+
+impl<F, X, const N: usize> Fn<SkipAt<X, N>> for Duplicated<F, X, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Right<X, N>: TupleUnprepend<Right<X, N>>,
+    ArgAt<X, N>: Clone,
+    Tail<Right<X, N>>: TupleIndex<0, Output = ArgAt<X, N>>,
+    F: Fn<X>,
+    (Left<X, N>, Tail<Right<X, N>>): TupleConcat<Left<X, N>, Tail<Right<X, N>>>,
+    SkipAt<X, N>: Tuple + SplitInto<Left<X, N>, Tail<Right<X, N>>>,
+    [(); <Left<X, N> as TupleLength>::LENGTH]:,
+    ((ArgAt<X, N>,), Tail<Right<X, N>>): TupleConcat<(ArgAt<X, N>,), Tail<Right<X, N>>, Type = Right<X, N>>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    extern "rust-call" fn call(&self, args: SkipAt<X, N>) -> Self::Output
+    {
+        let (before, after): (Left<X, N>, Tail<Right<X, N>>) = args.split_tuple();
+        let value = TupleIndex::<0>::tuple_index(&after).clone();
+        self.f.call(concat_tuples(before, concat_tuples((value,), after)))
+    }
+}
+
+/// Trait for accepting extra arguments after the arguments of a function, which are ignored.
+/// 
+/// ignore_extra::<(B,)>(f)(x, y) = f(x)
+/// 
+/// The ignored arguments XE must be given explicitly, since they can not be inferred from the function.
+/// 
+/// If f implements FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let f = |x: u8| x as f32;
+/// 
+/// // fitting a callback taking an index it does not need
+/// fn call_indexed(f: impl Fn(u8, usize) -> f32) -> f32
+/// {
+///     f(2, 0)
+/// }
+/// 
+/// assert_eq!(call_indexed(f.ignore_extra::<(usize,)>()), f(2));
+/// ```
+#[const_trait]
+pub trait IgnoreExtra<X>: Sized
+{
+    /// Ignoring extra arguments after the arguments of a function
+    /// 
+    /// ignore_extra::<(B,)>(f)(x, y) = f(x)
+    fn ignore_extra<XE>(self) -> IgnoringExtra<Self, X, XE>
+    where
+        XE: Tuple;
+}
+
+impl<F, X> const IgnoreExtra<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn ignore_extra<XE>(self) -> IgnoringExtra<Self, X, XE>
+    where
+        XE: Tuple
+    {
+        IgnoringExtra {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function accepting the extra arguments XE after its own, which are ignored.
+/// 
+/// See [ignore_extra](IgnoreExtra::ignore_extra).
+#[derive(Clone, Copy, Debug)]
+pub struct IgnoringExtra<F, X, XE>
+{
+    f: F,
+    phantom: PhantomData<(X, XE)>
+}
+
+impl<F, X, XE> FnOnce<ConcatTuples<X, XE>> for IgnoringExtra<F, X, XE>
+where
+    X: Tuple,
+    XE: Tuple,
+    F: FnOnce<X>,
+    (X, XE): TupleConcat<X, XE>,
+    ConcatTuples<X, XE>: Tuple + SplitInto<X, XE>,
+    [(); <X as TupleLength>::LENGTH]:
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: ConcatTuples<X, XE>) -> Self::Output
+    {
+        let (args, _): (X, XE) = args.split_tuple();
+        self.f.call_once(args)
+    }
+}
+
+impl<F, X, XE> FnMut<ConcatTuples<X, XE>> for IgnoringExtra<F, X, XE>
+where
+    X: Tuple,
+    XE: Tuple,
+    F: FnMut<X>,
+    (X, XE): TupleConcat<X, XE>,
+    ConcatTuples<X, XE>: Tuple + SplitInto<X, XE>,
+    [(); <X as TupleLength>::LENGTH]:
+{
+    extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<X, XE>) -> Self::Output
+    {
+        let (args, _): (X, XE) = args.split_tuple();
+        self.f.call_mut(args)
+    }
+}
+
+impl<F, X, XE> Fn<ConcatTuples<X, XE>> for IgnoringExtra<F, X, XE>
+where
+    X: Tuple,
+    XE: Tuple,
+    F: Fn<X>,
+    (X, XE): TupleConcat<X, XE>,
+    ConcatTuples<X, XE>: Tuple + SplitInto<X, XE>,
+    [(); <X as TupleLength>::LENGTH]:
+{
+    extern "rust-call" fn call(&self, args: ConcatTuples<X, XE>) -> Self::Output
+    {
+        let (args, _): (X, XE) = args.split_tuple();
+        self.f.call(args)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,323 @@
+use std::marker::{Tuple, PhantomData};
+
+/// Trait for a stage of a transactional workflow, whose effects can be undone after it has succeeded.
+/// 
+/// Running a stage returns its output, along with a log of what it did. If a later stage fails, the log is given back to [compensate](SagaStep::compensate), which undoes the stage.
+/// 
+/// This is implemented for [Saga](Saga) and [SagaComposition](SagaComposition). See [compensate_with](CompensateWith::compensate_with) and [saga_compose](SagaCompose::saga_compose).
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::RefCell;
+/// 
+/// use currycompose::*;
+/// 
+/// let balance = RefCell::new(100u32);
+/// 
+/// let mut withdraw = (|x: u32| -> Result<u32, &str> {
+///     let mut balance = balance.borrow_mut();
+///     *balance = balance.checked_sub(x).ok_or("insufficient funds")?;
+///     Ok(x)
+/// }).compensate_with(|x: u32| *balance.borrow_mut() += x);
+/// 
+/// let (x, log) = withdraw.run((30,)).unwrap();
+/// 
+/// assert_eq!(x, 30);
+/// assert_eq!(*balance.borrow(), 70);
+/// 
+/// // something else went wrong, so the withdrawal is undone
+/// withdraw.compensate(log);
+/// 
+/// assert_eq!(*balance.borrow(), 100);
+/// ```
+pub trait SagaStep<X>
+{
+    /// The output of the stage if it succeeds
+    type Output;
+    /// The error of the stage if it fails
+    type Error;
+    /// What is needed to undo the stage
+    type Log;
+
+    /// Runs the stage, returning its output and a log for undoing it.
+    /// 
+    /// If the stage fails, any stages it is composed of which had already succeeded are undone before the error is returned.
+    fn run(&mut self, args: X) -> Result<(Self::Output, Self::Log), Self::Error>;
+
+    /// Undoes a stage which has succeeded.
+    fn compensate(&mut self, log: Self::Log);
+}
+
+/// Trait for attaching a compensating action to a fallible function, turning it into a [stage](SagaStep) of a transactional workflow.
+/// 
+/// f must return a Result, and the compensation is called with a clone of its output, so the output must implement Clone.
+/// 
+/// Since a saga may be run several times, f and the compensation must implement FnMut.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::RefCell;
+/// 
+/// use currycompose::*;
+/// 
+/// let stock = RefCell::new(vec!["book", "pen"]);
+/// 
+/// let mut take = (|item: &'static str| {
+///     let mut stock = stock.borrow_mut();
+///     let i = stock.iter().position(|&x| x == item).ok_or("out of stock")?;
+///     Ok(stock.remove(i))
+/// }).compensate_with(|item| stock.borrow_mut().push(item));
+/// 
+/// // when run as a function, the compensation is never called
+/// assert_eq!(take("pen"), Ok("pen"));
+/// assert_eq!(take("pen"), Err("out of stock"));
+/// assert_eq!(*stock.borrow(), ["book"]);
+/// ```
+#[const_trait]
+pub trait CompensateWith<C, X>: Sized
+{
+    /// Attaching a compensating action to a fallible function
+    fn compensate_with(self, compensation: C) -> Saga<Self, C, X>;
+}
+
+impl<F, C, X, Y, E> const CompensateWith<C, X> for F
+where
+    X: Tuple,
+    Y: Clone,
+    F: FnMut<X, Output = Result<Y, E>>,
+    C: FnMut(Y)
+{
+    fn compensate_with(self, compensation: C) -> Saga<Self, C, X>
+    {
+        Saga {
+            f: self,
+            compensation,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a fallible function with a compensating action, which undoes it after it has succeeded.
+/// 
+/// When called as a function, the compensation is never called.
+/// 
+/// See [compensate_with](CompensateWith::compensate_with).
+#[derive(Clone, Copy, Debug)]
+pub struct Saga<F, C, X>
+{
+    f: F,
+    compensation: C,
+    phantom: PhantomData<X>
+}
+
+impl<F, C, X, Y, E> SagaStep<X> for Saga<F, C, X>
+where
+    X: Tuple,
+    Y: Clone,
+    F: FnMut<X, Output = Result<Y, E>>,
+    C: FnMut(Y)
+{
+    type Output = Y;
+    type Error = E;
+    type Log = Y;
+
+    fn run(&mut self, args: X) -> Result<(Self::Output, Self::Log), Self::Error>
+    {
+        let y = self.f.call_mut(args)?;
+        Ok((y.clone(), y))
+    }
+
+    fn compensate(&mut self, log: Self::Log)
+    {
+        (self.compensation)(log)
+    }
+}
+
+impl<F, C, X, Y, E> FnOnce<X> for Saga<F, C, X>
+where
+    X: Tuple,
+    F: FnOnce<X, Output = Result<Y, E>>
+{
+    type Output = Result<Y, E>;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        self.f.call_once(args)
+    }
+}
+
+impl<F, C, X, Y, E> FnMut<X> for Saga<F, C, X>
+where
+    X: Tuple,
+    F: FnMut<X, Output = Result<Y, E>>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        self.f.call_mut(args)
+    }
+}
+
+impl<F, C, X, Y, E> Fn<X> for Saga<F, C, X>
+where
+    X: Tuple,
+    F: Fn<X, Output = Result<Y, E>>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        self.f.call(args)
+    }
+}
+
+/// Trait for composing two [stages](SagaStep) of a transactional workflow, where the output of f is passed into g.
+/// 
+/// h(x) = g ∘ f = g(f(x)?)
+/// 
+/// If g fails, f is undone before the error is returned. If the composition is undone, g is undone first, then f, so compensations always run in the reverse order of the stages.
+/// Compositions are stages themselves, so they may be composed further, and a failing stage undoes every stage before it.
+/// 
+/// Both stages must have the same error type, and g must take exactly one argument.
+/// 
+/// The resulting composition implements FnOnce and FnMut, returning Result, and discarding the logs of the stages.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::RefCell;
+/// 
+/// use currycompose::*;
+/// 
+/// // in-memory stand-ins for side-effects
+/// let journal = RefCell::new(vec![]);
+/// 
+/// let reserve = (|order: u32| {
+///     journal.borrow_mut().push("reserve");
+///     Ok(order)
+/// }).compensate_with(|_| journal.borrow_mut().push("release"));
+/// 
+/// let charge = (|order: u32| {
+///     journal.borrow_mut().push("charge");
+///     Ok(order)
+/// }).compensate_with(|_| journal.borrow_mut().push("refund"));
+/// 
+/// let ship = (|order: u32| {
+///     if order == 0
+///     {
+///         return Err("no such address")
+///     }
+///     journal.borrow_mut().push("ship");
+///     Ok(order)
+/// }).compensate_with(|_| journal.borrow_mut().push("recall"));
+/// 
+/// // h ∘ g ∘ f
+/// // where
+/// // h :: u32 -> Result<u32, &str>
+/// // g :: u32 -> Result<u32, &str>
+/// // f :: u32 -> Result<u32, &str>
+/// // h ∘ g ∘ f :: u32 -> Result<u32, &str>
+/// let mut order = ship.saga_compose(charge.saga_compose(reserve));
+/// 
+/// assert_eq!(order(1), Ok(1));
+/// assert_eq!(journal.take(), ["reserve", "charge", "ship"]);
+/// 
+/// assert_eq!(order(0), Err("no such address"));
+/// assert_eq!(journal.take(), ["reserve", "charge", "refund", "release"]);
+/// 
+/// // the whole workflow may also be undone after it has succeeded
+/// let (_, log) = order.run((1,)).unwrap();
+/// order.compensate(log);
+/// 
+/// assert_eq!(journal.take(), ["reserve", "charge", "ship", "recall", "refund", "release"]);
+/// ```
+#[const_trait]
+pub trait SagaCompose<F, XF>: Sized
+{
+    /// Composing two stages of a transactional workflow
+    /// 
+    /// h(x) = g ∘ f = g(f(x)?)
+    fn saga_compose(self, with: F) -> SagaComposition<Self, F, XF>;
+}
+
+impl<G, F, XF> const SagaCompose<F, XF> for G
+where
+    XF: Tuple,
+    F: SagaStep<XF>,
+    G: SagaStep<(F::Output,), Error = F::Error>
+{
+    fn saga_compose(self, with: F) -> SagaComposition<Self, F, XF>
+    {
+        SagaComposition {
+            g: self,
+            f: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing two stages of a transactional workflow composed together.
+/// 
+/// See [saga_compose](SagaCompose::saga_compose).
+#[derive(Clone, Copy, Debug)]
+pub struct SagaComposition<G, F, XF>
+{
+    g: G,
+    f: F,
+    phantom: PhantomData<XF>
+}
+
+impl<G, F, XF> SagaStep<XF> for SagaComposition<G, F, XF>
+where
+    XF: Tuple,
+    F: SagaStep<XF>,
+    G: SagaStep<(F::Output,), Error = F::Error>
+{
+    type Output = G::Output;
+    type Error = G::Error;
+    type Log = (G::Log, F::Log);
+
+    fn run(&mut self, args: XF) -> Result<(Self::Output, Self::Log), Self::Error>
+    {
+        let (y, log_f) = self.f.run(args)?;
+        match self.g.run((y,))
+        {
+            Ok((z, log_g)) => Ok((z, (log_g, log_f))),
+            Err(error) => {
+                self.f.compensate(log_f);
+                Err(error)
+            }
+        }
+    }
+
+    fn compensate(&mut self, (log_g, log_f): Self::Log)
+    {
+        self.g.compensate(log_g);
+        self.f.compensate(log_f)
+    }
+}
+
+impl<G, F, XF> FnOnce<XF> for SagaComposition<G, F, XF>
+where
+    XF: Tuple,
+    F: SagaStep<XF>,
+    G: SagaStep<(F::Output,), Error = F::Error>
+{
+    type Output = Result<G::Output, G::Error>;
+
+    extern "rust-call" fn call_once(mut self, args: XF) -> Self::Output
+    {
+        self.call_mut(args)
+    }
+}
+
+impl<G, F, XF> FnMut<XF> for SagaComposition<G, F, XF>
+where
+    XF: Tuple,
+    F: SagaStep<XF>,
+    G: SagaStep<(F::Output,), Error = F::Error>
+{
+    extern "rust-call" fn call_mut(&mut self, args: XF) -> Self::Output
+    {
+        self.run(args).map(|(z, _)| z)
+    }
+}
\ No newline at end of file
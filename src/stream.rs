@@ -0,0 +1,210 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::MaybeDone;
+
+/// Trait for applying a function, such as a composition, to each item of a [Stream](futures_core::Stream).
+/// 
+/// The stream owns the function, and calls it through FnMut, so any state it keeps is shared between all items, just like with [Iterator::map](Iterator::map).
+/// 
+/// This requires the `stream` feature.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::future::{Future, ready};
+/// use std::pin::{Pin, pin};
+/// use std::task::{Context, Poll, Waker};
+/// 
+/// use currycompose::*;
+/// use futures_core::Stream;
+/// 
+/// // a stream of the items of an iterator
+/// struct Iter<I>(I);
+/// 
+/// impl<I: Iterator + Unpin> Stream for Iter<I>
+/// {
+///     type Item = I::Item;
+/// 
+///     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>>
+///     {
+///         Poll::Ready(self.0.next())
+///     }
+/// }
+/// 
+/// // a minimal executor, collecting the items of a stream
+/// fn collect<S: Stream>(stream: S) -> Vec<S::Item>
+/// {
+///     let mut stream = pin!(stream);
+///     let mut cx = Context::from_waker(Waker::noop());
+///     let mut items = vec![];
+///     loop
+///     {
+///         match stream.as_mut().poll_next(&mut cx)
+///         {
+///             Poll::Ready(Some(y)) => items.push(y),
+///             Poll::Ready(None) => return items,
+///             Poll::Pending => ()
+///         }
+///     }
+/// }
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: u32 -> u32
+/// // f :: u32 -> u32
+/// // g ∘ f :: u32 -> u32
+/// let mut sum = 0;
+/// let g = move |x: u32| {sum += x; sum};
+/// let f = |x: u32| x*x;
+/// 
+/// // the running sum is kept between items
+/// let squares = Iter(1..=4).map_composed(g.compose(f));
+/// 
+/// assert_eq!(collect(squares), [1, 5, 14, 30]);
+/// 
+/// // asynchronous functions may be driven several at a time, while still yielding their outputs in order
+/// let f = |x: u32| ready(x*2);
+/// 
+/// let doubled = Iter(1..=4).map_composed_async(f, 2);
+/// 
+/// assert_eq!(collect(doubled), [2, 4, 6, 8]);
+/// ```
+pub trait MapComposed: Stream + Sized
+{
+    /// Applying a function to each item of a stream
+    fn map_composed<H, Y>(self, h: H) -> MapComposedStream<Self, H>
+    where
+        H: FnMut(Self::Item) -> Y;
+
+    /// Applying an asynchronous function to each item of a stream, awaiting up to `limit` of its futures at once
+    /// 
+    /// The outputs are yielded in the same order as the items. A limit of 0 is treated as 1.
+    fn map_composed_async<H, FH>(self, h: H, limit: usize) -> MapComposedAsyncStream<Self, H, FH>
+    where
+        H: FnMut(Self::Item) -> FH,
+        FH: Future;
+}
+
+impl<S> MapComposed for S
+where
+    S: Stream
+{
+    fn map_composed<H, Y>(self, h: H) -> MapComposedStream<Self, H>
+    where
+        H: FnMut(Self::Item) -> Y
+    {
+        MapComposedStream {
+            stream: self,
+            h
+        }
+    }
+
+    fn map_composed_async<H, FH>(self, h: H, limit: usize) -> MapComposedAsyncStream<Self, H, FH>
+    where
+        H: FnMut(Self::Item) -> FH,
+        FH: Future
+    {
+        MapComposedAsyncStream {
+            stream: Some(self),
+            h,
+            limit: limit.max(1),
+            futures: VecDeque::new()
+        }
+    }
+}
+
+/// A stream applying a function to each item of another stream.
+/// 
+/// See [map_composed](MapComposed::map_composed).
+#[derive(Clone, Debug)]
+pub struct MapComposedStream<S, H>
+{
+    stream: S,
+    h: H
+}
+
+impl<S, H, Y> Stream for MapComposedStream<S, H>
+where
+    S: Stream,
+    H: FnMut(S::Item) -> Y
+{
+    type Item = Y;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>
+    {
+        // SAFETY: the stream is pinned along with the adapter, while the function is never pinned
+        let this = unsafe {self.get_unchecked_mut()};
+        match unsafe {Pin::new_unchecked(&mut this.stream)}.poll_next(cx)
+        {
+            Poll::Ready(x) => Poll::Ready(x.map(&mut this.h)),
+            Poll::Pending => Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.stream.size_hint()
+    }
+}
+
+/// A stream applying an asynchronous function to each item of another stream, awaiting several of its futures at once.
+/// 
+/// See [map_composed_async](MapComposed::map_composed_async).
+pub struct MapComposedAsyncStream<S, H, FH>
+where
+    FH: Future
+{
+    stream: Option<S>,
+    h: H,
+    limit: usize,
+    futures: VecDeque<Pin<Box<MaybeDone<FH>>>>
+}
+
+impl<S, H, FH> Stream for MapComposedAsyncStream<S, H, FH>
+where
+    S: Stream,
+    H: FnMut(S::Item) -> FH,
+    FH: Future
+{
+    type Item = FH::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>
+    {
+        // SAFETY: the stream is pinned along with the adapter, and only ever dropped in place, while everything else is never pinned
+        let this = unsafe {self.get_unchecked_mut()};
+        while this.futures.len() < this.limit
+        {
+            let Some(stream) = &mut this.stream
+            else
+            {
+                break
+            };
+            match unsafe {Pin::new_unchecked(stream)}.poll_next(cx)
+            {
+                Poll::Ready(Some(x)) => this.futures.push_back(Box::pin(MaybeDone::Pending((this.h)(x)))),
+                Poll::Ready(None) => this.stream = None,
+                Poll::Pending => break
+            }
+        }
+        for future in this.futures.iter_mut()
+        {
+            future.as_mut().poll_done(cx);
+        }
+        match this.futures.front_mut()
+        {
+            Some(future) if matches!(**future, MaybeDone::Done(_)) => {
+                let y = future.as_mut().take_output();
+                this.futures.pop_front();
+                Poll::Ready(Some(y))
+            },
+            Some(_) => Poll::Pending,
+            None if this.stream.is_none() => Poll::Ready(None),
+            None => Poll::Pending
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,435 @@
+use std::marker::{Tuple, PhantomData};
+use std::sync::{Mutex, PoisonError};
+use std::sync::mpsc::{self, Receiver, SendError, SyncSender};
+use std::thread::{self, JoinHandle};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength, TupleUnprepend, Head, Tail};
+
+use tuple_split::{TupleSplit, SplitInto};
+
+use crate::{Composition, CurryFront};
+
+/// A pipeline of stages running on separate threads, connected by bounded channels.
+/// 
+/// Arguments X are pushed in at one end, and outputs Y are pulled out at the other, in the same order.
+/// While one stage works on an input, the stage before it may already work on the next one.
+/// 
+/// Pushing and pulling only need a shared reference, so they may be done from different threads.
+/// 
+/// Dropping the pipeline closes it, waits for the stages to finish the inputs already pushed, and discards their outputs.
+/// 
+/// See [into_threaded](IntoThreaded::into_threaded).
+#[derive(Debug)]
+pub struct Threaded<X, Y>
+{
+    input: Option<SyncSender<X>>,
+    output: Mutex<Receiver<(Y, ())>>,
+    threads: Vec<JoinHandle<()>>
+}
+
+impl<X, Y> Threaded<X, Y>
+{
+    /// Pushes the arguments of the next call into the pipeline.
+    /// 
+    /// This blocks while the first stage's channel is full. If a stage has panicked, the arguments are given back.
+    pub fn push(&self, args: X) -> Result<(), SendError<X>>
+    {
+        match &self.input
+        {
+            Some(input) => input.send(args),
+            None => Err(SendError(args))
+        }
+    }
+
+    /// Pulls the output of the next call out of the pipeline.
+    /// 
+    /// This blocks until the output is ready, so it never returns while the pipeline is still open and no more inputs are pushed.
+    /// Returns None if a stage has panicked.
+    pub fn pull(&self) -> Option<Y>
+    {
+        self.output.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .recv()
+            .ok()
+            .map(|(y, ())| y)
+    }
+
+    /// Pulls the output of the next call out of the pipeline, if it is ready.
+    pub fn try_pull(&self) -> Option<Y>
+    {
+        self.output.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .try_recv()
+            .ok()
+            .map(|(y, ())| y)
+    }
+
+    /// Closes the pipeline, so that no more arguments can be pushed, returning an iterator over the outputs of the inputs already pushed.
+    pub fn close(mut self) -> ThreadedOutputs<X, Y>
+    {
+        self.input = None;
+        ThreadedOutputs {
+            pipeline: self
+        }
+    }
+}
+
+impl<X, Y> Drop for Threaded<X, Y>
+{
+    fn drop(&mut self)
+    {
+        self.input = None;
+        while self.pull().is_some() {}
+        for thread in self.threads.drain(..)
+        {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// An iterator over the remaining outputs of a closed pipeline, in order.
+/// 
+/// Since no more inputs can be pushed, the iterator ends once all the inputs already pushed have been processed.
+/// 
+/// See [close](Threaded::close).
+#[derive(Debug)]
+pub struct ThreadedOutputs<X, Y>
+{
+    pipeline: Threaded<X, Y>
+}
+
+impl<X, Y> Iterator for ThreadedOutputs<X, Y>
+{
+    type Item = Y;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.pipeline.pull()
+    }
+}
+
+fn spawn_stage<A, B>(input: Receiver<A>, capacity: usize, mut stage: impl FnMut(A) -> B + Send + 'static) -> (Receiver<B>, JoinHandle<()>)
+where
+    A: Send + 'static,
+    B: Send + 'static
+{
+    let (output, receiver) = mpsc::sync_channel(capacity);
+    let thread = thread::spawn(move || {
+        for a in input
+        {
+            if output.send(stage(a)).is_err()
+            {
+                break
+            }
+        }
+    });
+    (receiver, thread)
+}
+
+/// Trait for marking a function as a stage of a [threaded](IntoThreaded::into_threaded) pipeline, which runs on its own thread.
+/// 
+/// Compositions of stages are split into pipelines recursively, until reaching the functions marked as stages, so every function composed must be marked, either by itself or as part of a larger stage.
+/// 
+/// The stage forwards calls to f, so it implements FnOnce, FnMut or Fn if f does, and may be composed just like f.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let g = (|x: u8| x + 1).stage();
+/// let f = (|x: u8| x*2).stage();
+/// 
+/// // the stages may still be called directly
+/// assert_eq!(g(f(1)), 3);
+/// 
+/// let pipeline = g.compose(f).into_threaded(1);
+/// 
+/// pipeline.push((1,)).unwrap();
+/// 
+/// assert_eq!(pipeline.pull(), Some(3));
+/// ```
+/// 
+/// Functions which are not marked cannot be split into a pipeline.
+/// 
+/// ```rust,compile_fail
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let g = |x: u8| x + 1;
+/// let f = (|x: u8| x*2).stage();
+/// 
+/// // g is not a stage
+/// let pipeline = g.compose(f).into_threaded(1);
+/// ```
+#[const_trait]
+pub trait Stage<X>: Sized
+{
+    /// Marking a function as a stage of a threaded pipeline
+    fn stage(self) -> Staged<Self, X>;
+}
+
+impl<F, X> const Stage<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn stage(self) -> Staged<Self, X>
+    {
+        Staged {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function marked as a stage of a threaded pipeline.
+/// 
+/// See [stage](Stage::stage).
+#[derive(Clone, Copy, Debug)]
+pub struct Staged<F, X>
+{
+    f: F,
+    phantom: PhantomData<X>
+}
+
+impl<F, X> FnOnce<X> for Staged<F, X>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        self.f.call_once(args)
+    }
+}
+
+impl<F, X> FnMut<X> for Staged<F, X>
+where
+    X: Tuple,
+    F: FnMut<X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        self.f.call_mut(args)
+    }
+}
+
+impl<F, X> Fn<X> for Staged<F, X>
+where
+    X: Tuple,
+    F: Fn<X>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        self.f.call(args)
+    }
+}
+
+/// Helper trait for splitting a function into stages, each running on its own thread.
+/// 
+/// A [stage](Stage::stage) runs on a single thread, while a [Composition](crate::Composition) is split into the stages of f followed by the stages of g.
+/// Each stage takes its arguments X out of the values A it receives, and passes the rest C along with its output, to be picked up by a later stage.
+#[doc(hidden)]
+pub trait ThreadedStages<X>: FnMut<X>
+where
+    X: Tuple
+{
+    fn spawn_stages<A, C>(
+        self,
+        input: Receiver<A>,
+        split: impl FnMut(A) -> (X, C) + Send + 'static,
+        capacity: usize,
+        threads: &mut Vec<JoinHandle<()>>
+    ) -> Receiver<(<Self as FnOnce<X>>::Output, C)>
+    where
+        A: Send + 'static,
+        C: Send + 'static;
+}
+
+impl<F, X> ThreadedStages<X> for Staged<F, X>
+where
+    X: Tuple,
+    F: FnMut<X> + Send + 'static,
+    <F as FnOnce<X>>::Output: Send + 'static
+{
+    fn spawn_stages<A, C>(
+        mut self,
+        input: Receiver<A>,
+        mut split: impl FnMut(A) -> (X, C) + Send + 'static,
+        capacity: usize,
+        threads: &mut Vec<JoinHandle<()>>
+    ) -> Receiver<(<Self as FnOnce<X>>::Output, C)>
+    where
+        A: Send + 'static,
+        C: Send + 'static
+    {
+        let (output, thread) = spawn_stage(input, capacity, move |a| {
+            let (x, c) = split(a);
+            (self.f.call_mut(x), c)
+        });
+        threads.push(thread);
+        output
+    }
+}
+
+impl<G, F, XG, XF> ThreadedStages<ConcatTuples<Tail<XG>, XF>> for Composition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG> + Send + 'static,
+    XF: Tuple + Send + 'static,
+    G: ThreadedStages<XG> + Send + 'static,
+    F: ThreadedStages<XF, Output = Head<XG>> + Send + 'static,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<XG>): TupleConcat<(F::Output,), Tail<XG>, Type = XG>,
+    Tail<XG>: Send + 'static,
+    <G as FnOnce<XG>>::Output: Send + 'static,
+    <F as FnOnce<XF>>::Output: Send + 'static
+{
+    fn spawn_stages<A, C>(
+        self,
+        input: Receiver<A>,
+        mut split: impl FnMut(A) -> (ConcatTuples<Tail<XG>, XF>, C) + Send + 'static,
+        capacity: usize,
+        threads: &mut Vec<JoinHandle<()>>
+    ) -> Receiver<(<Self as FnOnce<ConcatTuples<Tail<XG>, XF>>>::Output, C)>
+    where
+        A: Send + 'static,
+        C: Send + 'static
+    {
+        let Composition {g, f, ..} = self;
+        let output_f = f.spawn_stages(input, move |a| {
+            let (args, c) = split(a);
+            let (left, right): (Tail<XG>, XF) = args.split_tuple();
+            (right, (left, c))
+        }, capacity, threads);
+        g.spawn_stages(output_f, |(y, (left, c))| (concat_tuples((y,), left), c), capacity, threads)
+    }
+}
+
+impl<G, F, XG, XF> ThreadedStages<ConcatTuples<XF, Tail<XG>>> for Composition<G, F, XG, XF, CurryFront>
+where
+    XG: Tuple + TupleUnprepend<XG> + Send + 'static,
+    XF: Tuple + Send + 'static,
+    G: ThreadedStages<XG> + Send + 'static,
+    F: ThreadedStages<XF, Output = Head<XG>> + Send + 'static,
+    (XF, Tail<XG>): TupleConcat<XF, Tail<XG>>,
+    ConcatTuples<XF, Tail<XG>>: Tuple + SplitInto<XF, Tail<XG>>,
+    [(); <XF as TupleLength>::LENGTH]:,
+    ((F::Output,), Tail<XG>): TupleConcat<(F::Output,), Tail<XG>, Type = XG>,
+    Tail<XG>: Send + 'static,
+    <G as FnOnce<XG>>::Output: Send + 'static,
+    <F as FnOnce<XF>>::Output: Send + 'static
+{
+    fn spawn_stages<A, C>(
+        self,
+        input: Receiver<A>,
+        mut split: impl FnMut(A) -> (ConcatTuples<XF, Tail<XG>>, C) + Send + 'static,
+        capacity: usize,
+        threads: &mut Vec<JoinHandle<()>>
+    ) -> Receiver<(<Self as FnOnce<ConcatTuples<XF, Tail<XG>>>>::Output, C)>
+    where
+        A: Send + 'static,
+        C: Send + 'static
+    {
+        let Composition {g, f, ..} = self;
+        let output_f = f.spawn_stages(input, move |a| {
+            let (args, c) = split(a);
+            let (left, right): (XF, Tail<XG>) = args.split_tuple();
+            (left, (right, c))
+        }, capacity, threads);
+        g.spawn_stages(output_f, |(y, (right, c))| (concat_tuples((y,), right), c), capacity, threads)
+    }
+}
+
+/// Trait for splitting a composition into a pipeline, with one thread per stage, connected by channels holding up to `capacity` values each.
+/// 
+/// Each function which should run on its own thread is marked with [stage](Stage::stage). Compositions nested inside f or g, such as those made with [compose!](crate::compose!), are split too, until reaching the stages.
+/// Marking the stages explicitly lets the pipeline tell them apart from the compositions being split, which it otherwise could not do for closures.
+/// The leftover arguments of each composition function are passed along through the pipeline, until they reach their stage.
+/// 
+/// Since the pipeline only ever calls each stage from a single thread, the stages need only implement FnMut and Send. Their arguments and outputs must also be Send.
+/// 
+/// Pushing more inputs than the channels can hold without pulling any outputs blocks, so pushing and pulling should be interleaved, or done from different threads.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::collections::HashSet;
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+/// 
+/// use currycompose::*;
+/// 
+/// // h ∘ g ∘ f
+/// // where
+/// // h :: u64 -> u64 -> u64
+/// // g :: u64 -> u64
+/// // f :: u64 -> u64
+/// // h ∘ g ∘ f :: u64 -> u64 -> u64
+/// let threads = Arc::new(Mutex::new(HashSet::new()));
+/// 
+/// let (t1, t2, t3) = (threads.clone(), threads.clone(), threads.clone());
+/// let mut count = 0;
+/// let h = (move |x: u64, m: u64| {t1.lock().unwrap().insert(thread::current().id()); count += 1; (x + count) % m}).stage();
+/// let g = (move |n: u64| {t2.lock().unwrap().insert(thread::current().id()); (1..=n).product::<u64>()}).stage();
+/// let f = (move |n: u64| {t3.lock().unwrap().insert(thread::current().id()); n + 1}).stage();
+/// 
+/// let pipeline = compose!(h, g, f).into_threaded(2);
+/// 
+/// thread::scope(|s| {
+///     s.spawn(|| for n in 0..10
+///     {
+///         pipeline.push((1000, n)).unwrap();
+///     });
+/// 
+///     let outputs: Vec<u64> = (0..10).map(|_| pipeline.pull().unwrap()).collect();
+/// 
+///     assert_eq!(outputs, [2, 4, 9, 28, 125, 726, 47, 328, 889, 810]);
+/// });
+/// 
+/// // each stage ran on its own thread
+/// assert_eq!(threads.lock().unwrap().len(), 3);
+/// 
+/// // once closed, the remaining outputs may be collected
+/// let g = (|x: u8, y: u8| x + y).stage();
+/// let f = (|x: u8| x*2).stage();
+/// 
+/// let pipeline = g.compose_front(f).into_threaded(4);
+/// 
+/// pipeline.push((1, 10)).unwrap();
+/// pipeline.push((2, 20)).unwrap();
+/// 
+/// assert_eq!(pipeline.close().collect::<Vec<_>>(), [12, 24]);
+/// ```
+pub trait IntoThreaded<X>: Sized
+where
+    X: Tuple
+{
+    /// Splitting a composition into a pipeline, with one thread per stage
+    fn into_threaded(self, capacity: usize) -> Threaded<X, <Self as FnOnce<X>>::Output>
+    where
+        Self: FnOnce<X>;
+}
+
+impl<T, X> IntoThreaded<X> for T
+where
+    X: Tuple + Send + 'static,
+    Self: ThreadedStages<X>
+{
+    fn into_threaded(self, capacity: usize) -> Threaded<X, <Self as FnOnce<X>>::Output>
+    {
+        let (input, receiver) = mpsc::sync_channel(capacity);
+        let mut threads = vec![];
+        let output = self.spawn_stages(receiver, |x| (x, ()), capacity, &mut threads);
+        Threaded {
+            input: Some(input),
+            output: Mutex::new(output),
+            threads
+        }
+    }
+}
\ No newline at end of file
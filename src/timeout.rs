@@ -0,0 +1,245 @@
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::marker::{Tuple, PhantomData};
+use std::ops::AsyncFnOnce;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// Trait for a source of time, which creates futures completing after a given duration.
+/// 
+/// This makes [timeouts](Timeout::timeout) independent of any particular runtime, and lets tests use a fake clock.
+pub trait Timer
+{
+    /// A future completing once the duration has passed
+    type Sleep: Future<Output = ()>;
+
+    /// Creates a future completing once the duration has passed.
+    fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+/// The error of an asynchronous function which did not complete before its deadline.
+/// 
+/// See [timeout](Timeout::timeout).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "deadline has elapsed")
+    }
+}
+
+impl Error for TimedOut {}
+
+/// Trait for giving an asynchronous function a deadline, after which it is cancelled.
+/// 
+/// The future returned by the function is dropped if it has not completed when the timer's sleep completes, and [TimedOut](TimedOut) is returned instead of its output.
+/// The timer is started when the function is called.
+/// 
+/// f must implement [AsyncFnOnce](std::ops::AsyncFnOnce), and the resulting function implements FnMut or Fn if f is a function returning a future implementing FnMut or Fn.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::cell::Cell;
+/// use std::future::{Future, pending, ready};
+/// use std::pin::{Pin, pin};
+/// use std::rc::Rc;
+/// use std::task::{Context, Poll, Waker};
+/// use std::time::Duration;
+/// 
+/// use currycompose::*;
+/// 
+/// // a fake clock, which only moves when told to
+/// #[derive(Clone, Default)]
+/// struct FakeClock(Rc<Cell<Duration>>);
+/// 
+/// struct FakeSleep(FakeClock, Duration);
+/// 
+/// impl Future for FakeSleep
+/// {
+///     type Output = ();
+/// 
+///     fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()>
+///     {
+///         if self.0.0.get() >= self.1 {Poll::Ready(())} else {Poll::Pending}
+///     }
+/// }
+/// 
+/// impl Timer for FakeClock
+/// {
+///     type Sleep = FakeSleep;
+/// 
+///     fn sleep(&self, duration: Duration) -> FakeSleep
+///     {
+///         FakeSleep(self.clone(), self.0.get() + duration)
+///     }
+/// }
+/// 
+/// // a minimal executor, moving the clock a millisecond forward whenever nothing is ready
+/// fn block_on<F: Future>(clock: &FakeClock, future: F) -> F::Output
+/// {
+///     let mut future = pin!(future);
+///     let mut cx = Context::from_waker(Waker::noop());
+///     loop
+///     {
+///         if let Poll::Ready(y) = future.as_mut().poll(&mut cx)
+///         {
+///             return y
+///         }
+///         clock.0.set(clock.0.get() + Duration::from_millis(1));
+///     }
+/// }
+/// 
+/// let clock = FakeClock::default();
+/// let deadline = Duration::from_millis(5);
+/// 
+/// // g ∘ (f₁, f₂)
+/// // where
+/// // g :: Result<u8, TimedOut> -> Result<u8, TimedOut> -> impl Future<Output = u8>
+/// // f₁ :: u8 -> impl Future<Output = Result<u8, TimedOut>>
+/// // f₂ :: () -> impl Future<Output = Result<u8, TimedOut>>
+/// // g ∘ (f₁, f₂) :: u8 -> impl Future<Output = u8>
+/// let g = |x: Result<u8, TimedOut>, y: Result<u8, TimedOut>| ready(x.unwrap_or(0) + y.unwrap_or(0));
+/// let f1 = (|x: u8| ready(x)).timeout(deadline, clock.clone());
+/// let f2 = (|| pending::<u8>()).timeout(deadline, clock.clone());
+/// 
+/// let gff = g.compose_join((f1, f2));
+/// 
+/// // the stage which never completes is cancelled after 5 ms
+/// assert_eq!(block_on(&clock, gff(1)), 1);
+/// assert_eq!(clock.0.get(), deadline);
+/// ```
+#[const_trait]
+pub trait Timeout<X, T>: Sized
+{
+    /// Giving an asynchronous function a deadline
+    fn timeout(self, duration: Duration, timer: T) -> TimingOut<Self, X, T>;
+}
+
+impl<F, X, T> const Timeout<X, T> for F
+where
+    X: Tuple,
+    Self: AsyncFnOnce<X>,
+    T: Timer
+{
+    fn timeout(self, duration: Duration, timer: T) -> TimingOut<Self, X, T>
+    {
+        TimingOut {
+            f: self,
+            duration,
+            timer,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing an asynchronous function with a deadline.
+/// 
+/// See [timeout](Timeout::timeout).
+#[derive(Clone, Copy, Debug)]
+pub struct TimingOut<F, X, T>
+{
+    f: F,
+    duration: Duration,
+    timer: T,
+    phantom: PhantomData<X>
+}
+
+/// The future returned by an asynchronous function with a deadline, which resolves to [TimedOut](TimedOut) if the sleep S completes before the future F.
+/// 
+/// See [timeout](Timeout::timeout).
+pub struct Deadline<F, S>
+{
+    future: F,
+    sleep: S
+}
+
+impl<F, S> Future for Deadline<F, S>
+where
+    F: Future,
+    S: Future<Output = ()>
+{
+    type Output = Result<F::Output, TimedOut>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        // SAFETY: both futures are pinned along with the deadline, and never moved out of it
+        let this = unsafe {self.get_unchecked_mut()};
+        if let Poll::Ready(y) = unsafe {Pin::new_unchecked(&mut this.future)}.poll(cx)
+        {
+            return Poll::Ready(Ok(y))
+        }
+        match unsafe {Pin::new_unchecked(&mut this.sleep)}.poll(cx)
+        {
+            Poll::Ready(()) => Poll::Ready(Err(TimedOut)),
+            Poll::Pending => Poll::Pending
+        }
+    }
+}
+
+impl<F, X, T> FnOnce<X> for TimingOut<F, X, T>
+where
+    X: Tuple,
+    F: AsyncFnOnce<X>,
+    T: Timer
+{
+    type Output = Deadline<F::CallOnceFuture, T::Sleep>;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        Deadline {
+            sleep: self.timer.sleep(self.duration),
+            future: self.f.async_call_once(args)
+        }
+    }
+}
+
+impl<F, X, T> FnMut<X> for TimingOut<F, X, T>
+where
+    X: Tuple,
+    F: FnMut<X> + AsyncFnOnce<X, CallOnceFuture = <F as FnOnce<X>>::Output>,
+    T: Timer
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        Deadline {
+            sleep: self.timer.sleep(self.duration),
+            future: self.f.call_mut(args)
+        }
+    }
+}
+
+impl<F, X, T> Fn<X> for TimingOut<F, X, T>
+where
+    X: Tuple,
+    F: Fn<X> + AsyncFnOnce<X, CallOnceFuture = <F as FnOnce<X>>::Output>,
+    T: Timer
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        Deadline {
+            sleep: self.timer.sleep(self.duration),
+            future: self.f.call(args)
+        }
+    }
+}
+
+impl<F, X, T> AsyncFnOnce<X> for TimingOut<F, X, T>
+where
+    X: Tuple,
+    F: AsyncFnOnce<X>,
+    T: Timer
+{
+    type Output = Result<F::Output, TimedOut>;
+    type CallOnceFuture = Deadline<F::CallOnceFuture, T::Sleep>;
+
+    extern "rust-call" fn async_call_once(self, args: X) -> Self::CallOnceFuture
+    {
+        self.call_once(args)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,181 @@
+use std::marker::{Tuple, PhantomData};
+use std::ops::{Try, FromResidual, ControlFlow};
+
+use tupleops::{ConcatTuples, TupleConcat, concat_tuples, TupleLength, TupleUnprepend, Head, Tail};
+
+use tuple_split::SplitInto;
+
+/// Trait for composing two fallible functions, where the output of f is unwrapped before being passed into g (Kleisli composition).
+/// 
+/// h(..., x) = g ∘ f = g(f(x)?, ...)
+/// 
+/// f may return any type implementing [Try](std::ops::Try), such as Result or Option, whose output is the first argument of g.
+/// If f short-circuits, g is not called, and the residual is converted into the output of g, just like the `?` operator does.
+/// For Result, this means the error of f is converted into the error of g using [From](From).
+/// 
+/// As with [compose](crate::Compose::compose), the leftover arguments of g come first, then the arguments of f.
+/// 
+/// Both operands must implement FnOnce. If both implement FnMut or Fn, the resulting composition will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use std::num::ParseIntError;
+/// 
+/// use currycompose::*;
+/// 
+/// #[derive(Debug, PartialEq)]
+/// enum Error
+/// {
+///     Parse(ParseIntError),
+///     DivideByZero
+/// }
+/// 
+/// impl From<ParseIntError> for Error
+/// {
+///     fn from(error: ParseIntError) -> Self
+///     {
+///         Error::Parse(error)
+///     }
+/// }
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: u32 -> u32 -> Result<u32, Error>
+/// // f :: &str -> Result<u32, ParseIntError>
+/// // g ∘ f :: u32 -> &str -> Result<u32, Error>
+/// let g = |x: u32, y: u32| x.checked_div(y).ok_or(Error::DivideByZero);
+/// let f = |x: &str| x.parse::<u32>();
+/// 
+/// let gf = g.try_compose(f);
+/// 
+/// assert_eq!(gf(2, "6"), Ok(3));
+/// assert_eq!(gf(0, "6"), Err(Error::DivideByZero));
+/// assert_eq!(gf(2, "six"), Err(Error::Parse("six".parse::<u32>().unwrap_err())));
+/// 
+/// // works the same with Option
+/// let g = |x: u32| x.checked_sub(1);
+/// let f = |x: u32| x.checked_mul(2);
+/// 
+/// let gf = g.try_compose(f);
+/// 
+/// assert_eq!(gf(3), Some(5));
+/// assert_eq!(gf(u32::MAX), None);
+/// ```
+#[const_trait]
+pub trait TryCompose<F, XG, XF>: Sized
+{
+    /// Composing two fallible functions
+    /// 
+    /// h(x) = g ∘ f = g(f(x)?)
+    fn try_compose(self, with: F) -> TryComposition<Self, F, XG, XF>;
+}
+
+impl<G, F, XG, XF> const TryCompose<F, XG, XF> for G
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    Self: FnOnce<XG>,
+    F: FnOnce<XF>,
+    F::Output: Try<Output = Head<XG>>,
+    <Self as FnOnce<XG>>::Output: FromResidual<<F::Output as Try>::Residual>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple,
+    TryComposition<Self, F, XG, XF>: FnOnce<ConcatTuples<Tail<XG>, XF>>
+{
+    fn try_compose(self, with: F) -> TryComposition<Self, F, XG, XF>
+    {
+        TryComposition {
+            g: self,
+            f: with,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a fallible function composed with another.
+/// 
+/// When calling the composition as a function, the leftover arguments of the composition function come first (if curried), then the arguments of the function being composed with.
+/// 
+/// See [try_compose](TryCompose::try_compose).
+#[derive(Clone, Copy, Debug)]
+pub struct TryComposition<G, F, XG, XF>
+{
+    g: G,
+    f: F,
+    phantom: PhantomData<(XG, XF)>
+}
+
+impl<G, F, XG, XF> FnOnce<ConcatTuples<Tail<XG>, XF>> for TryComposition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: FnOnce<XG>,
+    F: FnOnce<XF>,
+    F::Output: Try<Output = Head<XG>>,
+    G::Output: FromResidual<<F::Output as Try>::Residual>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:,
+    ((Head<XG>,), Tail<XG>): TupleConcat<(Head<XG>,), Tail<XG>, Type = XG>
+{
+    type Output = <G as FnOnce<XG>>::Output;
+
+    extern "rust-call" fn call_once(self, args: ConcatTuples<Tail<XG>, XF>) -> Self::Output
+    {
+        let (left, right): (Tail<XG>, XF) = args.split_tuple();
+        match self.f.call_once(right).branch()
+        {
+            ControlFlow::Continue(x) => self.g.call_once(concat_tuples((x,), left)),
+            ControlFlow::Break(residual) => G::Output::from_residual(residual)
+        }
+    }
+}
+
+impl<G, F, XG, XF> FnMut<ConcatTuples<Tail<XG>, XF>> for TryComposition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: FnMut<XG>,
+    F: FnMut<XF>,
+    F::Output: Try<Output = Head<XG>>,
+    G::Output: FromResidual<<F::Output as Try>::Residual>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:,
+    ((Head<XG>,), Tail<XG>): TupleConcat<(Head<XG>,), Tail<XG>, Type = XG>
+{
+    extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<Tail<XG>, XF>) -> Self::Output
+    {
+        let (left, right): (Tail<XG>, XF) = args.split_tuple();
+        match self.f.call_mut(right).branch()
+        {
+            ControlFlow::Continue(x) => self.g.call_mut(concat_tuples((x,), left)),
+            ControlFlow::Break(residual) => G::Output::from_residual(residual)
+        }
+    }
+}
+
+impl<G, F, XG, XF> Fn<ConcatTuples<Tail<XG>, XF>> for TryComposition<G, F, XG, XF>
+where
+    XG: Tuple + TupleUnprepend<XG>,
+    XF: Tuple,
+    G: Fn<XG>,
+    F: Fn<XF>,
+    F::Output: Try<Output = Head<XG>>,
+    G::Output: FromResidual<<F::Output as Try>::Residual>,
+    (Tail<XG>, XF): TupleConcat<Tail<XG>, XF>,
+    ConcatTuples<Tail<XG>, XF>: Tuple + SplitInto<Tail<XG>, XF>,
+    [(); <Tail<XG> as TupleLength>::LENGTH]:,
+    ((Head<XG>,), Tail<XG>): TupleConcat<(Head<XG>,), Tail<XG>, Type = XG>
+{
+    extern "rust-call" fn call(&self, args: ConcatTuples<Tail<XG>, XF>) -> Self::Output
+    {
+        let (left, right): (Tail<XG>, XF) = args.split_tuple();
+        match self.f.call(right).branch()
+        {
+            ControlFlow::Continue(x) => self.g.call(concat_tuples((x,), left)),
+            ControlFlow::Break(residual) => G::Output::from_residual(residual)
+        }
+    }
+}
\ No newline at end of file
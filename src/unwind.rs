@@ -0,0 +1,404 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::marker::{Tuple, PhantomData};
+use std::panic::{self, AssertUnwindSafe, UnwindSafe, RefUnwindSafe};
+
+/// Identifies a stage of a composition, either by its index or by its name.
+/// 
+/// See [label](Label::label).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StageId
+{
+    Index(usize),
+    Name(&'static str)
+}
+
+impl From<usize> for StageId
+{
+    fn from(index: usize) -> Self
+    {
+        StageId::Index(index)
+    }
+}
+
+impl From<&'static str> for StageId
+{
+    fn from(name: &'static str) -> Self
+    {
+        StageId::Name(name)
+    }
+}
+
+impl fmt::Display for StageId
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            StageId::Index(index) => write!(f, "#{}", index),
+            StageId::Name(name) => write!(f, "{}", name)
+        }
+    }
+}
+
+/// The error of a function which panicked, carrying the innermost [labeled](Label::label) stage it panicked in, if any.
+/// 
+/// See [catch_unwind](CatchUnwind::catch_unwind).
+pub struct StagePanic
+{
+    stage: Option<StageId>,
+    payload: Box<dyn Any + Send>
+}
+
+impl StagePanic
+{
+    /// The innermost labeled stage which panicked, if any.
+    pub fn stage(&self) -> Option<StageId>
+    {
+        self.stage
+    }
+
+    /// The panic message, if the panic was raised with a string.
+    pub fn message(&self) -> Option<&str>
+    {
+        match self.payload.downcast_ref::<&'static str>()
+        {
+            Some(message) => Some(message),
+            None => self.payload.downcast_ref::<String>().map(String::as_str)
+        }
+    }
+
+    /// The payload the function panicked with.
+    pub fn into_payload(self) -> Box<dyn Any + Send>
+    {
+        self.payload
+    }
+
+    /// Continues unwinding with the original payload.
+    /// 
+    /// If the panic is caught again by an outer [catch_unwind](CatchUnwind::catch_unwind), it is still reported as coming from the same stage.
+    pub fn resume_unwind(self) -> !
+    {
+        if CATCHING.get() > 0
+        {
+            panic::resume_unwind(Box::new(self))
+        }
+        panic::resume_unwind(self.payload)
+    }
+}
+
+impl fmt::Debug for StagePanic
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("StagePanic")
+            .field("stage", &self.stage)
+            .field("message", &self.message())
+            .finish()
+    }
+}
+
+impl fmt::Display for StagePanic
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self.stage
+        {
+            Some(stage) => write!(f, "stage {} panicked", stage)?,
+            None => write!(f, "unlabeled stage panicked")?
+        }
+        match self.message()
+        {
+            Some(message) => write!(f, ": {}", message),
+            None => Ok(())
+        }
+    }
+}
+
+impl std::error::Error for StagePanic {}
+
+/// Trait for labeling a stage of a composition, so that panics inside it can be traced back to it.
+/// 
+/// The label may be an index or a name, see [StageId](StageId). It has no effect unless the panic is caught by [catch_unwind](CatchUnwind::catch_unwind), and if labeled stages are nested, the innermost label is reported.
+/// Outside of catch_unwind, the panic itself is left untouched, so code catching it by other means, such as [std::panic::catch_unwind](std::panic::catch_unwind) or [JoinHandle::join](std::thread::JoinHandle::join), still gets the original payload.
+/// Inside it, the stage is carried along with the panic by rewrapping its payload into a [StagePanic](StagePanic), which is what code catching it there by other means gets instead.
+/// 
+/// Labeling a stage does not require it to be UnwindSafe, since panics are only caught by it to be resumed at once. If f implements FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: u32 -> u32
+/// // f :: u32 -> u32
+/// // g ∘ f :: u32 -> u32
+/// let g = (|x: u32| 100/x).label("divide");
+/// let f = (|x: u32| x.checked_sub(1).unwrap()).label("decrement");
+/// 
+/// let gf = g.compose(f).catch_unwind();
+/// 
+/// assert_eq!(gf(6).unwrap(), 20);
+/// assert_eq!(gf(1).unwrap_err().stage(), Some(StageId::Name("divide")));
+/// assert_eq!(gf(0).unwrap_err().stage(), Some(StageId::Name("decrement")));
+/// 
+/// // caught by other means, the panic keeps its original payload
+/// let h = (|| panic!("bad input")).label("parse");
+/// 
+/// let payload = std::panic::catch_unwind(|| h()).unwrap_err();
+/// 
+/// assert_eq!(payload.downcast_ref::<&str>(), Some(&"bad input"));
+/// 
+/// // a panic caught inside a stage does not count towards a later one
+/// let inner = (|x: u32| 100/x).label("inner");
+/// let outer = (move |x: u32| -> u32 {
+///     let _ = std::panic::catch_unwind(|| inner(x));
+///     panic!("outer")
+/// }).label("outer");
+/// 
+/// assert_eq!(outer.catch_unwind()(0).unwrap_err().stage(), Some(StageId::Name("outer")));
+/// ```
+#[const_trait]
+pub trait Label<X>: Sized
+{
+    /// Labeling a stage of a composition
+    fn label<I>(self, id: I) -> Labeled<Self, X, I>
+    where
+        I: Into<StageId> + Copy;
+}
+
+impl<F, X> const Label<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn label<I>(self, id: I) -> Labeled<Self, X, I>
+    where
+        I: Into<StageId> + Copy
+    {
+        Labeled {
+            f: self,
+            id,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a labeled stage of a composition.
+/// 
+/// See [label](Label::label).
+#[derive(Clone, Copy, Debug)]
+pub struct Labeled<F, X, I>
+{
+    f: F,
+    id: I,
+    phantom: PhantomData<X>
+}
+
+thread_local! {
+    // the number of catch_unwind calls currently running on this thread
+    static CATCHING: Cell<usize> = const {Cell::new(0)};
+}
+
+struct CatchingGuard;
+
+impl CatchingGuard
+{
+    fn enter() -> Self
+    {
+        CATCHING.set(CATCHING.get() + 1);
+        CatchingGuard
+    }
+}
+
+impl Drop for CatchingGuard
+{
+    fn drop(&mut self)
+    {
+        CATCHING.set(CATCHING.get() - 1)
+    }
+}
+
+fn call_labeled<Y>(id: StageId, f: impl FnOnce() -> Y) -> Y
+{
+    // without a catch_unwind to report the stage to, the panic is left untouched
+    if CATCHING.get() == 0
+    {
+        return f()
+    }
+    // the stage travels with the payload, so it cannot outlive the panic if that is caught by other means
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        if payload.is::<StagePanic>()
+        {
+            // an inner stage has already been recorded
+            panic::resume_unwind(payload)
+        }
+        panic::resume_unwind(Box::new(StagePanic {
+            stage: Some(id),
+            payload
+        }))
+    })
+}
+
+fn call_catching<Y>(f: impl FnOnce() -> Y + UnwindSafe) -> Result<Y, StagePanic>
+{
+    let _guard = CatchingGuard::enter();
+    panic::catch_unwind(f).map_err(|payload| match payload.downcast::<StagePanic>()
+    {
+        Ok(error) => *error,
+        Err(payload) => StagePanic {
+            stage: None,
+            payload
+        }
+    })
+}
+
+impl<F, X, I> FnOnce<X> for Labeled<F, X, I>
+where
+    X: Tuple,
+    F: FnOnce<X>,
+    I: Into<StageId> + Copy
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        let f = self.f;
+        call_labeled(self.id.into(), move || f.call_once(args))
+    }
+}
+
+impl<F, X, I> FnMut<X> for Labeled<F, X, I>
+where
+    X: Tuple,
+    F: FnMut<X>,
+    I: Into<StageId> + Copy
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        let f = &mut self.f;
+        call_labeled(self.id.into(), move || f.call_mut(args))
+    }
+}
+
+impl<F, X, I> Fn<X> for Labeled<F, X, I>
+where
+    X: Tuple,
+    F: Fn<X>,
+    I: Into<StageId> + Copy
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        let f = &self.f;
+        call_labeled(self.id.into(), move || f.call(args))
+    }
+}
+
+/// Trait for catching panics inside a function, such as a composition, converting them into a Result.
+/// 
+/// If the function panics, the error tells which [labeled](Label::label) stage it panicked in, if any. See [StagePanic](StagePanic).
+/// 
+/// As with [std::panic::catch_unwind](std::panic::catch_unwind), the function and its arguments must be UnwindSafe, since they may be observed after a panic.
+/// When called by value, f must implement UnwindSafe, and for the resulting function to implement Fn, f must also implement RefUnwindSafe, since it may be called again after it has panicked.
+/// The resulting function implements FnMut if f implements FnMut and UnwindSafe, but that does not cover the mutable borrow it is called through, so if f is called again after a caught panic, making sure its state is still valid is up to the caller.
+/// Functions capturing mutable references may be wrapped in [AssertUnwindSafe](std::panic::AssertUnwindSafe).
+/// 
+/// Note that the panic hook still runs, so the panic is printed as usual, unless the hook is replaced.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let parse = (|x: &str| x.parse::<i32>().unwrap()).label(0);
+/// let invert = (|x: i32| 1000/x).label(1);
+/// 
+/// let service = invert.compose(parse).catch_unwind();
+/// 
+/// // the service survives bad input
+/// for (input, stage) in [("ten", StageId::Index(0)), ("0", StageId::Index(1))]
+/// {
+///     let error = service(input).unwrap_err();
+///     assert_eq!(error.stage(), Some(stage));
+/// }
+/// 
+/// assert_eq!(service("10").unwrap(), 100);
+/// 
+/// // panics outside labeled stages are caught too
+/// let error = (|| panic!("oops")).catch_unwind()().unwrap_err();
+/// 
+/// assert_eq!(error.stage(), None);
+/// assert_eq!(error.message(), Some("oops"));
+/// ```
+#[const_trait]
+pub trait CatchUnwind<X>: Sized
+{
+    /// Catching panics inside a function
+    fn catch_unwind(self) -> CatchingUnwind<Self, X>;
+}
+
+impl<F, X> const CatchUnwind<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn catch_unwind(self) -> CatchingUnwind<Self, X>
+    {
+        CatchingUnwind {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function whose panics are caught and converted into a Result.
+/// 
+/// See [catch_unwind](CatchUnwind::catch_unwind).
+#[derive(Clone, Copy, Debug)]
+pub struct CatchingUnwind<F, X>
+{
+    f: F,
+    phantom: PhantomData<X>
+}
+
+impl<F, X> FnOnce<X> for CatchingUnwind<F, X>
+where
+    X: Tuple + UnwindSafe,
+    F: FnOnce<X> + UnwindSafe
+{
+    type Output = Result<F::Output, StagePanic>;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        let f = self.f;
+        call_catching(move || f.call_once(args))
+    }
+}
+
+impl<F, X> FnMut<X> for CatchingUnwind<F, X>
+where
+    X: Tuple + UnwindSafe,
+    F: FnMut<X> + UnwindSafe
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        // the caller is responsible for any state of f left broken by a caught panic, see CatchUnwind
+        let f = &mut self.f;
+        call_catching(AssertUnwindSafe(move || f.call_mut(args)))
+    }
+}
+
+impl<F, X> Fn<X> for CatchingUnwind<F, X>
+where
+    X: Tuple + UnwindSafe,
+    F: Fn<X> + UnwindSafe + RefUnwindSafe
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        let f = &self.f;
+        call_catching(move || f.call(args))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,323 @@
+use std::marker::{Tuple, PhantomData};
+
+use crate::ConcatArgs;
+
+/// Trait for values which may be combined associatively, such as collections of errors.
+/// 
+/// Any semigroup S may collect the errors of a [validation](ValidateAll::validate_all), as long as each error can be converted into S.
+pub trait Semigroup
+{
+    /// Combines two values into one.
+    fn combine(self, other: Self) -> Self;
+}
+
+impl Semigroup for String
+{
+    fn combine(mut self, other: Self) -> Self
+    {
+        self.push_str(&other);
+        self
+    }
+}
+
+/// Trait for collecting the errors E of a [validation](ValidateAll::validate_all).
+/// 
+/// This is implemented for Vec<E>, and for any [Semigroup](Semigroup) which E can be converted into.
+pub trait Accumulate<E>
+{
+    /// Starts a collection with the first error.
+    fn from_error(error: E) -> Self;
+
+    /// Adds another error to the collection.
+    fn accumulate(self, error: E) -> Self;
+}
+
+impl<E> Accumulate<E> for Vec<E>
+{
+    fn from_error(error: E) -> Self
+    {
+        vec![error]
+    }
+
+    fn accumulate(mut self, error: E) -> Self
+    {
+        self.push(error);
+        self
+    }
+}
+
+impl<S, E> Accumulate<E> for S
+where
+    S: Semigroup,
+    E: Into<S>
+{
+    fn from_error(error: E) -> Self
+    {
+        error.into()
+    }
+
+    fn accumulate(self, error: E) -> Self
+    {
+        self.combine(error.into())
+    }
+}
+
+/// Helper trait for unwrapping the result of a validation, collecting its error into ES.
+#[doc(hidden)]
+pub trait Validate
+{
+    type Output;
+    type Error;
+
+    fn validate<ES>(self, errors: &mut Option<ES>) -> Option<Self::Output>
+    where
+        ES: Accumulate<Self::Error>;
+}
+
+impl<Y, E> Validate for Result<Y, E>
+{
+    type Output = Y;
+    type Error = E;
+
+    fn validate<ES>(self, errors: &mut Option<ES>) -> Option<Self::Output>
+    where
+        ES: Accumulate<Self::Error>
+    {
+        match self
+        {
+            Ok(y) => Some(y),
+            Err(error) => {
+                *errors = Some(match errors.take()
+                {
+                    Some(errors) => errors.accumulate(error),
+                    None => ES::from_error(error)
+                });
+                None
+            }
+        }
+    }
+}
+
+/// Trait for composing a function with a tuple of validations, where g is only called if all of them succeed, and otherwise all of their errors are collected.
+/// 
+/// h(x₁, x₂, ...) = g ∘ (f₁, f₂, ...) = Ok(g(f₁(x₁)?, f₂(x₂)?, ...))
+/// 
+/// Unlike [try_compose](crate::TryCompose::try_compose), every validation is run, even after one has failed, and the errors are collected in order into ES.
+/// ES may be Vec<E>, or any [Semigroup](Semigroup) which the errors can be converted into. See [Accumulate](Accumulate).
+/// 
+/// The arguments of each validation are concatenated, in order, into the argument-list of the composition, just like with [compose_all](crate::ComposeAll::compose_all).
+/// 
+/// All operands must implement FnOnce. If all of them implement FnMut or Fn, the resulting composition will also implement these traits.
+/// 
+/// g must have exactly as many arguments as there are validations, and there may be up to 6 validations.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// #[derive(Debug, PartialEq)]
+/// struct User
+/// {
+///     name: String,
+///     age: u8
+/// }
+/// 
+/// let name = |name: &str| if name.is_empty() {Err("name is empty")} else {Ok(name.to_string())};
+/// let age = |age: i32| u8::try_from(age).map_err(|_| "age is out of range");
+/// 
+/// let user = (|name, age| User {name, age}).validate_all::<Vec<_>>((name, age));
+/// 
+/// assert_eq!(user("Ada", 36), Ok(User {name: "Ada".to_string(), age: 36}));
+/// assert_eq!(user("", 36), Err(vec!["name is empty"]));
+/// assert_eq!(user("", -1), Err(vec!["name is empty", "age is out of range"]));
+/// ```
+/// 
+/// The errors may also be collected into a user-supplied semigroup.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// #[derive(Debug, PartialEq)]
+/// struct Count(usize);
+/// 
+/// impl Semigroup for Count
+/// {
+///     fn combine(self, other: Self) -> Self
+///     {
+///         Count(self.0 + other.0)
+///     }
+/// }
+/// 
+/// impl From<()> for Count
+/// {
+///     fn from((): ()) -> Self
+///     {
+///         Count(1)
+///     }
+/// }
+/// 
+/// let positive = |x: i32| if x > 0 {Ok(x)} else {Err(())};
+/// 
+/// let sum = (|x: i32, y: i32, z: i32| x + y + z).validate_all::<Count>((positive, positive, positive));
+/// 
+/// assert_eq!(sum(1, 2, 3), Ok(6));
+/// assert_eq!(sum(1, -2, -3), Err(Count(2)));
+/// ```
+/// 
+/// Each validation must return a Result, which is checked when the composition is built.
+/// 
+/// ```rust,compile_fail
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// let positive = |x: i32| if x > 0 {Some(x)} else {None};
+/// 
+/// // Option is not a validation
+/// let _ = (|x: i32| x).validate_all::<Vec<()>>((positive,));
+/// ```
+#[const_trait]
+pub trait ValidateAll<FS, XG, XFS>: Sized
+{
+    /// Composing a function with a tuple of validations, collecting their errors into ES
+    /// 
+    /// h(x₁, x₂, ...) = g ∘ (f₁, f₂, ...) = Ok(g(f₁(x₁)?, f₂(x₂)?, ...))
+    fn validate_all<ES>(self, with: FS) -> ValidationComposition<Self, FS, XG, XFS, ES>;
+}
+
+/// A struct representing a function composed with a tuple of validations, one for each of its arguments, collecting their errors into ES.
+/// 
+/// See [validate_all](ValidateAll::validate_all).
+#[derive(Clone, Copy, Debug)]
+pub struct ValidationComposition<G, FS, XG, XFS, ES>
+{
+    g: G,
+    fs: FS,
+    phantom: PhantomData<(XG, XFS, ES)>,
+}
+
+macro_rules! impl_validate_all {
+    (($f0:ident, $g0:ident, $x0:ident, $v0:ident, $y0:ident) $(, ($f:ident, $g:ident, $x:ident, $v:ident, $y:ident))*) => {
+        impl<G, $f0, $($f,)* $x0, $($x,)*> const ValidateAll<($f0, $($f,)*), (<$f0::Output as Validate>::Output, $(<$f::Output as Validate>::Output,)*), ($x0, $($x,)*)> for G
+        where
+            Self: FnOnce<(<$f0::Output as Validate>::Output, $(<$f::Output as Validate>::Output,)*)>,
+            $x0: Tuple,
+            $($x: Tuple,)*
+            $f0: FnOnce<$x0>,
+            $($f: FnOnce<$x>,)*
+            $f0::Output: Validate,
+            $($f::Output: Validate,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            fn validate_all<ES>(self, with: ($f0, $($f,)*)) -> ValidationComposition<Self, ($f0, $($f,)*), (<$f0::Output as Validate>::Output, $(<$f::Output as Validate>::Output,)*), ($x0, $($x,)*), ES>
+            {
+                ValidationComposition {
+                    g: self,
+                    fs: with,
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)* ES> FnOnce<<($x0, $($x,)*) as ConcatArgs>::Type> for ValidationComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*), ES>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: FnOnce<($y0, $($y,)*)>,
+            $f0: FnOnce<$x0>,
+            $($f: FnOnce<$x>,)*
+            $f0::Output: Validate<Output = $y0>,
+            $($f::Output: Validate<Output = $y>,)*
+            ES: Accumulate<<$f0::Output as Validate>::Error>,
+            $(ES: Accumulate<<$f::Output as Validate>::Error>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            type Output = Result<<G as FnOnce<($y0, $($y,)*)>>::Output, ES>;
+
+            extern "rust-call" fn call_once(self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = self.fs;
+                let mut errors = None;
+                let $v0 = $g0.call_once($v0).validate(&mut errors);
+                $(let $v = $g.call_once($v).validate(&mut errors);)*
+                match ($v0, $($v,)*)
+                {
+                    (Some($v0), $(Some($v),)*) => Ok(self.g.call_once(($v0, $($v,)*))),
+                    _ => Err(errors.expect("a failed validation always records its error"))
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)* ES> FnMut<<($x0, $($x,)*) as ConcatArgs>::Type> for ValidationComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*), ES>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: FnMut<($y0, $($y,)*)>,
+            $f0: FnMut<$x0>,
+            $($f: FnMut<$x>,)*
+            $f0::Output: Validate<Output = $y0>,
+            $($f::Output: Validate<Output = $y>,)*
+            ES: Accumulate<<$f0::Output as Validate>::Error>,
+            $(ES: Accumulate<<$f::Output as Validate>::Error>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            extern "rust-call" fn call_mut(&mut self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = &mut self.fs;
+                let mut errors = None;
+                let $v0 = $g0.call_mut($v0).validate(&mut errors);
+                $(let $v = $g.call_mut($v).validate(&mut errors);)*
+                match ($v0, $($v,)*)
+                {
+                    (Some($v0), $(Some($v),)*) => Ok(self.g.call_mut(($v0, $($v,)*))),
+                    _ => Err(errors.expect("a failed validation always records its error"))
+                }
+            }
+        }
+
+        impl<G, $f0, $($f,)* $x0, $($x,)* $y0, $($y,)* ES> Fn<<($x0, $($x,)*) as ConcatArgs>::Type> for ValidationComposition<G, ($f0, $($f,)*), ($y0, $($y,)*), ($x0, $($x,)*), ES>
+        where
+            $x0: Tuple,
+            $($x: Tuple,)*
+            G: Fn<($y0, $($y,)*)>,
+            $f0: Fn<$x0>,
+            $($f: Fn<$x>,)*
+            $f0::Output: Validate<Output = $y0>,
+            $($f::Output: Validate<Output = $y>,)*
+            ES: Accumulate<<$f0::Output as Validate>::Error>,
+            $(ES: Accumulate<<$f::Output as Validate>::Error>,)*
+            ($x0, $($x,)*): ConcatArgs
+        {
+            extern "rust-call" fn call(&self, args: <($x0, $($x,)*) as ConcatArgs>::Type) -> Self::Output
+            {
+                let ($v0, $($v,)*) = <($x0, $($x,)*) as ConcatArgs>::split_args(args);
+                let ($g0, $($g,)*) = &self.fs;
+                let mut errors = None;
+                let $v0 = $g0.call($v0).validate(&mut errors);
+                $(let $v = $g.call($v).validate(&mut errors);)*
+                match ($v0, $($v,)*)
+                {
+                    (Some($v0), $(Some($v),)*) => Ok(self.g.call(($v0, $($v,)*))),
+                    _ => Err(errors.expect("a failed validation always records its error"))
+                }
+            }
+        }
+
+        impl_validate_all!($(($f, $g, $x, $v, $y)),*);
+    };
+    () => {};
+}
+
+impl_validate_all!(
+    (F1, f1, X1, x1, Y1),
+    (F2, f2, X2, x2, Y2),
+    (F3, f3, X3, x3, Y3),
+    (F4, f4, X4, x4, Y4),
+    (F5, f5, X5, x5, Y5),
+    (F6, f6, X6, x6, Y6)
+);
\ No newline at end of file
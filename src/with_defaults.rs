@@ -0,0 +1,113 @@
+use std::marker::{Tuple, PhantomData};
+
+use tupleops::{TupleConcat, concat_tuples};
+
+use tuple_split::{TupleSplit, Left, Right};
+
+/// Trait for exposing only the first N arguments of a function, filling the rest with their default values.
+/// 
+/// with_defaults::<1>(f)(x) = f(x, Default::default(), ...)
+/// 
+/// The default values are created anew on every call, so if f implements FnMut or Fn, the resulting function will also implement these traits.
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use currycompose::*;
+/// 
+/// // a function with a trailing configuration argument
+/// fn scale(x: f32, factor: Option<f32>) -> f32
+/// {
+///     x*factor.unwrap_or(2.0)
+/// }
+/// 
+/// // g ∘ f
+/// // where
+/// // g :: f32 -> f32
+/// // f :: u8 -> f32
+/// // g ∘ f :: u8 -> f32
+/// let g = scale.with_defaults::<1>();
+/// let f = |x: u8| x as f32;
+/// 
+/// let gf = g.compose(f);
+/// 
+/// let x = 3;
+/// 
+/// assert_eq!(gf(x), scale(f(x), None));
+/// ```
+#[const_trait]
+pub trait WithDefaults<X>: Sized
+{
+    /// Exposing only the first N arguments of a function
+    /// 
+    /// with_defaults::<1>(f)(x) = f(x, Default::default(), ...)
+    fn with_defaults<const N: usize>(self) -> Defaulted<Self, X, N>;
+}
+
+impl<F, X> const WithDefaults<X> for F
+where
+    X: Tuple,
+    Self: FnOnce<X>
+{
+    fn with_defaults<const N: usize>(self) -> Defaulted<Self, X, N>
+    {
+        Defaulted {
+            f: self,
+            phantom: PhantomData
+        }
+    }
+}
+
+/// A struct representing a function taking only its first N arguments, with the rest filled with their default values.
+/// 
+/// See [with_defaults](WithDefaults::with_defaults).
+#[derive(Clone, Copy, Debug)]
+pub struct Defaulted<F, X, const N: usize>
+{
+    f: F,
+    phantom: PhantomData<X>
+}
+
+impl<F, X, const N: usize> FnOnce<Left<X, N>> for Defaulted<F, X, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Left<X, N>: Tuple,
+    Right<X, N>: Default,
+    F: FnOnce<X>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: Left<X, N>) -> Self::Output
+    {
+        self.f.call_once(concat_tuples(args, Right::<X, N>::default()))
+    }
+}
+
+impl<F, X, const N: usize> FnMut<Left<X, N>> for Defaulted<F, X, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Left<X, N>: Tuple,
+    Right<X, N>: Default,
+    F: FnMut<X>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: Left<X, N>) -> Self::Output
+    {
+        self.f.call_mut(concat_tuples(args, Right::<X, N>::default()))
+    }
+}
+
+impl<F, X, const N: usize> Fn<Left<X, N>> for Defaulted<F, X, N>
+where
+    X: Tuple + TupleSplit<N>,
+    Left<X, N>: Tuple,
+    Right<X, N>: Default,
+    F: Fn<X>,
+    (Left<X, N>, Right<X, N>): TupleConcat<Left<X, N>, Right<X, N>, Type = X>
+{
+    extern "rust-call" fn call(&self, args: Left<X, N>) -> Self::Output
+    {
+        self.f.call(concat_tuples(args, Right::<X, N>::default()))
+    }
+}
\ No newline at end of file